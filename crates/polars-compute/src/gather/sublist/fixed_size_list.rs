@@ -1,4 +1,5 @@
 use arrow::array::{ArrayRef, FixedSizeListArray, PrimitiveArray};
+use arrow::compute::utils::combine_validities_and;
 use arrow::legacy::prelude::*;
 use arrow::legacy::utils::CustomIterTools;
 use polars_error::{PolarsResult, polars_bail};
@@ -49,6 +50,9 @@ pub fn sub_fixed_size_list_get_literal(
     if !null_on_oob && take_by.null_count() > 0 {
         polars_bail!(ComputeError: "get index is out of bounds");
     }
+    // Null rows have no element to get, regardless of what their values hold.
+    let validity = combine_validities_and(take_by.validity(), arr.validity());
+    let take_by = take_by.with_validity(validity);
 
     let values = arr.values();
     // SAFETY:
@@ -65,6 +69,9 @@ pub fn sub_fixed_size_list_get(
     if !null_on_oob && take_by.null_count() > 0 {
         polars_bail!(ComputeError: "get index is out of bounds");
     }
+    // Null rows have no element to get, regardless of what their values hold.
+    let validity = combine_validities_and(take_by.validity(), arr.validity());
+    let take_by = take_by.with_validity(validity);
 
     let values = arr.values();
     // SAFETY:
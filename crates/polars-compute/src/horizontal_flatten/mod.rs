@@ -3,8 +3,9 @@ use arrow::array::{
     Array, ArrayCollectIterExt, BinaryArray, BinaryViewArray, BooleanArray, FixedSizeListArray,
     ListArray, NullArray, PrimitiveArray, StaticArray, StructArray, Utf8ViewArray,
 };
-use arrow::bitmap::Bitmap;
+use arrow::bitmap::{Bitmap, BitmapBuilder};
 use arrow::datatypes::{ArrowDataType, PhysicalType};
+use arrow::types::NativeType;
 use arrow::with_match_primitive_type_full;
 use strength_reduce::StrengthReducedUsize;
 mod struct_;
@@ -34,25 +35,49 @@ pub unsafe fn horizontal_flatten_unchecked(
             dtype.clone(),
             output_height * widths.iter().copied().sum::<usize>(),
         )),
-        Boolean => Box::new(horizontal_flatten_unchecked_impl_generic(
-            &arrays
+        Boolean => {
+            let arrays = arrays
                 .iter()
                 .map(|x| x.as_any().downcast_ref::<BooleanArray>().unwrap().clone())
-                .collect::<Vec<_>>(),
-            widths,
-            output_height,
-            dtype,
-        )),
+                .collect::<Vec<_>>();
+
+            if can_copy_rows(&arrays, widths, output_height) {
+                Box::new(horizontal_flatten_unchecked_boolean_no_nulls(
+                    &arrays,
+                    widths,
+                    output_height,
+                    dtype,
+                ))
+            } else {
+                Box::new(horizontal_flatten_unchecked_impl_generic(
+                    &arrays,
+                    widths,
+                    output_height,
+                    dtype,
+                ))
+            }
+        },
         Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
-            Box::new(horizontal_flatten_unchecked_impl_generic(
-                &arrays
-                    .iter()
-                    .map(|x| x.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap().clone())
-                    .collect::<Vec<_>>(),
-                widths,
-                output_height,
-                dtype
-            ))
+            let arrays = arrays
+                .iter()
+                .map(|x| x.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap().clone())
+                .collect::<Vec<_>>();
+
+            if can_copy_rows(&arrays, widths, output_height) {
+                Box::new(horizontal_flatten_unchecked_primitive_no_nulls(
+                    &arrays,
+                    widths,
+                    output_height,
+                    dtype
+                ))
+            } else {
+                Box::new(horizontal_flatten_unchecked_impl_generic(
+                    &arrays,
+                    widths,
+                    output_height,
+                    dtype
+                ))
+            }
         }),
         LargeBinary => Box::new(horizontal_flatten_unchecked_impl_generic(
             &arrays
@@ -126,6 +151,56 @@ pub unsafe fn horizontal_flatten_unchecked(
     }
 }
 
+/// Whether the rows of `arrays` can be copied as contiguous slices, which requires that no array
+/// has nulls or needs to be broadcasted.
+fn can_copy_rows<T: Array>(arrays: &[T], widths: &[usize], output_height: usize) -> bool {
+    output_height > 1
+        && arrays
+            .iter()
+            .zip(widths)
+            .all(|(arr, width)| arr.null_count() == 0 && arr.len() == output_height * *width)
+}
+
+/// Fast path of [`horizontal_flatten_unchecked_impl_generic`] for primitive arrays without nulls.
+unsafe fn horizontal_flatten_unchecked_primitive_no_nulls<T: NativeType>(
+    arrays: &[PrimitiveArray<T>],
+    widths: &[usize],
+    output_height: usize,
+    dtype: &ArrowDataType,
+) -> PrimitiveArray<T> {
+    let out_row_width: usize = widths.iter().copied().sum();
+    let mut values = Vec::with_capacity(out_row_width.checked_mul(output_height).unwrap());
+
+    for row_idx in 0..output_height {
+        for (arr, width) in arrays.iter().zip(widths) {
+            let start = row_idx * *width;
+            values.extend_from_slice(arr.values().get_unchecked(start..start + *width));
+        }
+    }
+
+    PrimitiveArray::new(dtype.clone(), values.into(), None)
+}
+
+/// Fast path of [`horizontal_flatten_unchecked_impl_generic`] for boolean arrays without nulls.
+unsafe fn horizontal_flatten_unchecked_boolean_no_nulls(
+    arrays: &[BooleanArray],
+    widths: &[usize],
+    output_height: usize,
+    dtype: &ArrowDataType,
+) -> BooleanArray {
+    let out_row_width: usize = widths.iter().copied().sum();
+    let mut values =
+        BitmapBuilder::with_capacity(out_row_width.checked_mul(output_height).unwrap());
+
+    for row_idx in 0..output_height {
+        for (arr, width) in arrays.iter().zip(widths) {
+            values.subslice_extend_from_bitmap(arr.values(), row_idx * *width, *width);
+        }
+    }
+
+    BooleanArray::new(dtype.clone(), values.freeze(), None)
+}
+
 unsafe fn horizontal_flatten_unchecked_impl_generic<T>(
     arrays: &[T],
     widths: &[usize],
@@ -1,6 +1,7 @@
 use polars_core::error::{PolarsResult, polars_bail, polars_ensure, polars_err};
 use polars_core::prelude::{Column, DataType, ExplodeOptions, IntoColumn, SortOptions};
 use polars_ops::prelude::array::ArrayNameSpace;
+use polars_ops::series::concat_arr::concat_arr_output_dtype;
 #[cfg(feature = "array_to_struct")]
 use polars_plan::dsl::DslNameGenerator;
 use polars_plan::dsl::{ColumnsUdf, SpecialEq};
@@ -12,7 +13,7 @@ use super::*;
 pub fn function_expr_to_udf(func: IRArrayFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
     use IRArrayFunction::*;
     match func {
-        Concat => map_as_slice!(concat_arr),
+        Concat { list_width } => map_as_slice!(concat_arr, list_width),
         Length => map!(length),
         Min => map!(min),
         Max => map!(max),
@@ -197,56 +198,20 @@ pub(super) fn shift(s: &[Column]) -> PolarsResult<Column> {
 
 pub(super) fn slice(s: &Column, offset: i64, length: i64) -> PolarsResult<Column> {
     let ca = s.array()?;
-    ca.array_slice(offset, length).map(Column::from)
+    ca.array_slice(offset, length, false).map(Column::from)
 }
 
 fn explode(c: &[Column], options: ExplodeOptions) -> PolarsResult<Column> {
     c[0].explode(options)
 }
 
-fn concat_arr(args: &[Column]) -> PolarsResult<Column> {
-    let dtype = concat_arr_output_dtype(&mut args.iter().map(|c| (c.name().as_str(), c.dtype())))?;
-
-    polars_ops::series::concat_arr::concat_arr(args, &dtype)
-}
-
-/// Determine the output dtype of a `concat_arr` operation. Also performs validation to ensure input
-/// dtypes are compatible.
-fn concat_arr_output_dtype(
-    inputs: &mut dyn ExactSizeIterator<Item = (&str, &DataType)>,
-) -> PolarsResult<DataType> {
-    #[allow(clippy::len_zero)]
-    if inputs.len() == 0 {
-        // should not be reachable - we did not set ALLOW_EMPTY_INPUTS
-        panic!();
-    }
-
-    let mut inputs = inputs.map(|(name, dtype)| {
-        let (inner_dtype, width) = match dtype {
-            DataType::Array(inner, width) => (inner.as_ref(), *width),
-            dt => (dt, 1),
-        };
-        (name, dtype, inner_dtype, width)
-    });
-    let (first_name, first_dtype, first_inner_dtype, mut out_width) = inputs.next().unwrap();
-
-    for (col_name, dtype, inner_dtype, width) in inputs {
-        out_width += width;
-
-        if inner_dtype != first_inner_dtype {
-            polars_bail!(
-                SchemaMismatch:
-                "concat_arr dtype mismatch: expected {} or array[{}] dtype to match dtype of first \
-                input column (name: {}, dtype: {}), got {} instead for column {}",
-                first_inner_dtype, first_inner_dtype, first_name, first_dtype, dtype, col_name,
-            )
-        }
-    }
+fn concat_arr(args: &[Column], list_width: Option<usize>) -> PolarsResult<Column> {
+    let dtype = concat_arr_output_dtype(
+        &mut args.iter().map(|c| (c.name().as_str(), c.dtype())),
+        list_width,
+    )?;
 
-    Ok(DataType::Array(
-        Box::new(first_inner_dtype.clone()),
-        out_width,
-    ))
+    polars_ops::series::concat_arr::concat_arr(args, &dtype, list_width)
 }
 
 #[cfg(feature = "array_to_struct")]
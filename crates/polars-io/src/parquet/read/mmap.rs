@@ -53,6 +53,7 @@ pub fn to_deserializer(
     columns: Vec<(&ColumnChunkMetadata, MemSlice)>,
     field: Field,
     filter: Option<Filter>,
+    verify_crc: bool,
 ) -> PolarsResult<(Vec<Box<dyn Array>>, Bitmap)> {
     let (columns, types): (Vec<_>, Vec<_>) = columns
         .into_iter()
@@ -60,7 +61,8 @@ pub fn to_deserializer(
             // Advise fetching the data for the column chunk
             chunk.prefetch();
 
-            let pages = PageReader::new(MemReader::new(chunk), column_meta, vec![], usize::MAX);
+            let pages = PageReader::new(MemReader::new(chunk), column_meta, vec![], usize::MAX)
+                .with_crc_verification(verify_crc);
             (
                 BasicDecompressor::new(pages, vec![]),
                 &column_meta.descriptor().descriptor.primitive_type,
@@ -104,6 +104,7 @@ fn column_idx_to_series(
     filter: Option<Filter>,
     file_schema: &ArrowSchema,
     store: &mmap::ColumnStore,
+    verify_crc: bool,
 ) -> PolarsResult<(Series, Bitmap)> {
     let field = file_schema.get_at_index(column_i).unwrap().1;
 
@@ -112,7 +113,8 @@ fn column_idx_to_series(
         assert_dtypes(field.dtype())
     }
     let columns = mmap_columns(store, field_md);
-    let (arrays, pred_true_mask) = mmap::to_deserializer(columns, field.clone(), filter)?;
+    let (arrays, pred_true_mask) =
+        mmap::to_deserializer(columns, field.clone(), filter, verify_crc)?;
     let series = Series::try_from((field, arrays))?;
 
     Ok((series, pred_true_mask))
@@ -131,6 +133,7 @@ fn rg_to_dfs(
     parallel: ParallelStrategy,
     projection: &[usize],
     hive_partition_columns: Option<&[Series]>,
+    verify_crc: bool,
 ) -> PolarsResult<Vec<DataFrame>> {
     if config::verbose() {
         eprintln!("parquet scan with parallel = {parallel:?}");
@@ -167,6 +170,7 @@ fn rg_to_dfs(
             parallel,
             projection,
             hive_partition_columns,
+            verify_crc,
         ),
         _ => rg_to_dfs_par_over_rg(
             store,
@@ -179,6 +183,7 @@ fn rg_to_dfs(
             row_index,
             projection,
             hive_partition_columns,
+            verify_crc,
         ),
     }
 }
@@ -197,6 +202,7 @@ fn rg_to_dfs_optionally_par_over_columns(
     parallel: ParallelStrategy,
     projection: &[usize],
     hive_partition_columns: Option<&[Series]>,
+    verify_crc: bool,
 ) -> PolarsResult<Vec<DataFrame>> {
     let mut dfs = Vec::with_capacity(row_group_end - row_group_start);
 
@@ -233,6 +239,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                 Some(Filter::new_ranged(rg_slice.0, rg_slice.0 + rg_slice.1)),
                 schema,
                 store,
+                verify_crc,
             )?;
 
             try_set_sorted_flag(&mut series, *column_i, &sorting_map);
@@ -294,6 +301,7 @@ fn rg_to_dfs_par_over_rg(
     row_index: Option<RowIndex>,
     projection: &[usize],
     hive_partition_columns: Option<&[Series]>,
+    verify_crc: bool,
 ) -> PolarsResult<Vec<DataFrame>> {
     // compute the limits per row group and the row count offsets
     let mut row_groups = Vec::with_capacity(row_group_end - row_group_start);
@@ -376,6 +384,7 @@ fn rg_to_dfs_par_over_rg(
                             Some(Filter::new_ranged(slice.0, slice.0 + slice.1)),
                             schema,
                             store,
+                            verify_crc,
                         )?;
 
                         try_set_sorted_flag(&mut series, *column_i, &sorting_map);
@@ -413,6 +422,7 @@ pub fn read_parquet<R: MmapBytesReader>(
     mut parallel: ParallelStrategy,
     row_index: Option<RowIndex>,
     hive_partition_columns: Option<&[Series]>,
+    verify_crc: bool,
 ) -> PolarsResult<DataFrame> {
     // Fast path.
     if pre_slice.1 == 0 {
@@ -463,6 +473,7 @@ pub fn read_parquet<R: MmapBytesReader>(
         parallel,
         &materialized_projection,
         hive_partition_columns,
+        verify_crc,
     )?;
 
     if dfs.is_empty() {
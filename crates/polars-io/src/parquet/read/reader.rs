@@ -27,6 +27,7 @@ pub struct ParquetReader<R: Read + Seek> {
     metadata: Option<FileMetadataRef>,
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
+    verify_crc: bool,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -163,6 +164,13 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Verify the CRC32 checksum of every page that has one, erroring on a mismatch. This costs
+    /// a pass over the page data, so it is off by default.
+    pub fn with_crc_verification(mut self, verify_crc: bool) -> Self {
+        self.verify_crc = verify_crc;
+        self
+    }
+
     pub fn set_metadata(&mut self, metadata: FileMetadataRef) {
         self.metadata = Some(metadata);
     }
@@ -191,6 +199,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             hive_partition_columns: None,
             include_file_path: None,
+            verify_crc: false,
         }
     }
 
@@ -217,6 +226,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.parallel,
             self.row_index,
             self.hive_partition_columns.as_deref(),
+            self.verify_crc,
         )?;
 
         if self.rechunk {
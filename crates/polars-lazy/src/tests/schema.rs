@@ -46,3 +46,54 @@ fn test_schema_update_after_projection_pd() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-array")]
+fn test_concat_arr_schema() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1i32, 2],
+        "b" => [3i64, 4],
+    ]?;
+
+    let schema = df
+        .clone()
+        .lazy()
+        .select([concat_arr(vec![col("a"), col("b")], None)?])
+        .collect_schema()?;
+    assert_eq!(
+        schema.get("a"),
+        Some(&DataType::Array(Box::new(DataType::Int64), 2))
+    );
+
+    // By default list columns are elements of the output.
+    let schema = df
+        .clone()
+        .lazy()
+        .select([concat_arr(
+            vec![col("a").implode(), col("b").implode()],
+            None,
+        )?])
+        .collect_schema()?;
+    assert_eq!(
+        schema.get("a"),
+        Some(&DataType::Array(
+            Box::new(DataType::List(Box::new(DataType::Int64))),
+            2
+        ))
+    );
+
+    // With a list width they are flattened, the planned schema must match the output.
+    let lf = df.lazy().select([concat_arr(
+        vec![col("a").implode(), col("b").implode()],
+        Some(2),
+    )?]);
+    let schema = lf.clone().collect_schema()?;
+    assert_eq!(
+        schema.get("a"),
+        Some(&DataType::Array(Box::new(DataType::Int64), 4))
+    );
+    let out = lf.collect()?;
+    assert_eq!(out.schema().as_ref(), schema.as_ref());
+
+    Ok(())
+}
@@ -4,15 +4,19 @@ use arrow::legacy::utils::CustomIterTools;
 
 use super::*;
 
-fn array_all_any<F>(arr: &FixedSizeListArray, op: F, is_all: bool) -> PolarsResult<BooleanArray>
-where
-    F: Fn(&BooleanArray) -> bool,
-{
+fn boolean_values(arr: &FixedSizeListArray) -> PolarsResult<&BooleanArray> {
     let values = arr.values();
 
     polars_ensure!(values.dtype() == &ArrowDataType::Boolean, ComputeError: "expected boolean elements in array");
 
-    let values = values.as_any().downcast_ref::<BooleanArray>().unwrap();
+    Ok(values.as_any().downcast_ref::<BooleanArray>().unwrap())
+}
+
+fn array_all_any<F>(arr: &FixedSizeListArray, op: F, is_all: bool) -> PolarsResult<BooleanArray>
+where
+    F: Fn(&BooleanArray) -> bool,
+{
+    let values = boolean_values(arr)?;
     let validity = arr.validity().cloned();
 
     // Fast path where all values set (all is free).
@@ -25,9 +29,9 @@ where
     }
 
     let len = arr.size();
-    let iter = (0..values.len()).step_by(len).map(|start| {
+    let iter = (0..arr.len()).map(|row| {
         // SAFETY: start + len is in bound guarded by invariant of FixedSizeListArray
-        let val = unsafe { values.clone().sliced_unchecked(start, len) };
+        let val = unsafe { values.clone().sliced_unchecked(row * len, len) };
         op(&val)
     });
 
@@ -38,6 +42,25 @@ where
     .with_validity(validity))
 }
 
+/// Same as [`array_all_any`], but `op` follows Kleene logic, so a row can also evaluate to null.
+fn array_all_any_kleene<F>(arr: &FixedSizeListArray, op: F) -> PolarsResult<BooleanArray>
+where
+    F: Fn(&BooleanArray) -> Option<bool>,
+{
+    let values = boolean_values(arr)?;
+    let len = arr.size();
+    Ok((0..arr.len())
+        .map(|row| {
+            if !arr.is_valid(row) {
+                return None;
+            }
+            // SAFETY: start + len is in bound guarded by invariant of FixedSizeListArray
+            let val = unsafe { values.clone().sliced_unchecked(row * len, len) };
+            op(&val)
+        })
+        .collect())
+}
+
 pub(super) fn array_all(ca: &ArrayChunked) -> PolarsResult<Series> {
     let chunks = ca
         .downcast_iter()
@@ -51,3 +74,17 @@ pub(super) fn array_any(ca: &ArrayChunked) -> PolarsResult<Series> {
         .map(|arr| array_all_any(arr, arrow::compute::boolean::any, false));
     Ok(BooleanChunked::try_from_chunk_iter(ca.name().clone(), chunks)?.into_series())
 }
+
+pub(super) fn array_all_kleene(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| array_all_any_kleene(arr, arrow::compute::boolean_kleene::all));
+    Ok(BooleanChunked::try_from_chunk_iter(ca.name().clone(), chunks)?.into_series())
+}
+
+pub(super) fn array_any_kleene(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| array_all_any_kleene(arr, arrow::compute::boolean_kleene::any));
+    Ok(BooleanChunked::try_from_chunk_iter(ca.name().clone(), chunks)?.into_series())
+}
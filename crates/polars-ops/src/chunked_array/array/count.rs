@@ -17,6 +17,17 @@ pub fn array_count_matches(ca: &ArrayChunked, value: AnyValue) -> PolarsResult<S
     Ok(out.into_series())
 }
 
+/// Whether every row contains `value`. A null `value` looks for null elements.
+pub fn array_contains(ca: &ArrayChunked, value: AnyValue) -> PolarsResult<BooleanChunked> {
+    let value = Series::new(PlSmallStr::EMPTY, [value]).strict_cast(ca.inner_dtype())?;
+
+    let matches = ca.apply_to_inner(&|s| {
+        ChunkCompareEq::<&Series>::equal_missing(&s, &value).map(|ca| ca.into_series())
+    })?;
+    let out = count_boolean_bits(&matches);
+    Ok(out.gt(0).with_name(ca.name().clone()))
+}
+
 pub(super) fn count_boolean_bits(ca: &ArrayChunked) -> IdxCa {
     unary_mut_with_options(ca, |arr| {
         let inner_arr = arr.values();
@@ -73,6 +73,30 @@ pub(super) fn median_with_nulls(ca: &ArrayChunked) -> PolarsResult<Series> {
     Ok(out)
 }
 
+pub(super) fn quantile_with_nulls(
+    ca: &ArrayChunked,
+    quantile: f64,
+    method: QuantileMethod,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "`quantile` should be between 0.0 and 1.0",
+    );
+    let out: Float64Chunked = ca
+        .try_apply_amortized_generic(|s| {
+            let Some(s) = s else {
+                return Ok(None);
+            };
+            let q = s.as_ref().quantile_reduce(quantile, method)?;
+            Ok(q.value().extract::<f64>())
+        })?
+        .with_name(ca.name().clone());
+    match ca.inner_dtype() {
+        DataType::Float32 => out.cast(&DataType::Float32),
+        _ => Ok(out.into_series()),
+    }
+}
+
 pub(super) fn std_with_nulls(ca: &ArrayChunked, ddof: u8) -> PolarsResult<Series> {
     let mut out = match ca.inner_dtype() {
         #[cfg(feature = "dtype-f16")]
@@ -1,4 +1,6 @@
 use arrow::array::{Array, PrimitiveArray};
+use arrow::bitmap::Bitmap;
+use arrow::compute::utils::combine_validities_and;
 use polars_compute::min_max::MinMaxKernel;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
@@ -67,19 +69,26 @@ where
     )
 }
 
+/// `values` must be the single-chunk values of an array with the given `width` and row
+/// `validity`.
 pub(super) fn array_dispatch(
     name: PlSmallStr,
     values: &Series,
     width: usize,
+    validity: Option<&Bitmap>,
     agg_type: AggType,
 ) -> Series {
     let chunks: Vec<ArrayRef> = with_match_physical_numeric_polars_type!(values.dtype(), |$T| {
         let ca: &ChunkedArray<$T> = values.as_ref().as_ref().as_ref();
+        debug_assert_eq!(ca.chunks().len(), 1);
         ca.downcast_iter().map(|arr| {
-            match agg_type {
-                AggType::Min => Box::new(agg_min(arr, width)) as ArrayRef,
-                AggType::Max => Box::new(agg_max(arr, width)) as ArrayRef,
-            }
+            let out = match agg_type {
+                AggType::Min => agg_min(arr, width),
+                AggType::Max => agg_max(arr, width),
+            };
+            // Null rows still hold values, those must not be aggregated into a valid output.
+            let out_validity = combine_validities_and(out.validity(), validity);
+            Box::new(out.with_validity(out_validity)) as ArrayRef
         }).collect()
     });
     Series::try_from((name, chunks)).unwrap()
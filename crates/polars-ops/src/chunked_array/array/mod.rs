@@ -10,7 +10,7 @@ mod sum_mean;
 #[cfg(feature = "array_to_struct")]
 mod to_struct;
 
-pub use namespace::ArrayNameSpace;
+pub use namespace::{ArrZipOp, ArrayNameSpace, NormOrd, PadSide, ShiftFill};
 use polars_core::prelude::*;
 #[cfg(feature = "array_to_struct")]
 pub use to_struct::*;
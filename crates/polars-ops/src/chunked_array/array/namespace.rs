@@ -1,21 +1,36 @@
 use arrow::array::builder::{ShareStrategy, make_builder};
-use arrow::array::{Array, FixedSizeListArray};
+use arrow::array::{Array, FixedSizeListArray, ListArray};
 use arrow::bitmap::BitmapBuilder;
+use arrow::compute::utils::combine_validities_and;
+use arrow::offset::OffsetsBuffer;
+use polars_core::chunked_array::builder::get_list_builder;
 use polars_core::prelude::arity::unary_kernel;
-use polars_core::utils::slice_offsets;
+#[cfg(feature = "diff")]
+use polars_core::series::ops::NullBehavior;
+use polars_core::utils::{slice_offsets, try_get_supertype};
 
 use super::min_max::AggType;
 use super::*;
 #[cfg(feature = "array_count")]
 use crate::chunked_array::array::count::array_count_matches;
-use crate::chunked_array::array::count::count_boolean_bits;
+use crate::chunked_array::array::count::{array_contains, count_boolean_bits};
 use crate::chunked_array::array::sum_mean::sum_with_nulls;
+#[cfg(any(feature = "list_gather", feature = "mode"))]
+use crate::chunked_array::list::ListNameSpaceImpl;
+#[cfg(feature = "mode")]
+use crate::chunked_array::mode::mode;
 #[cfg(feature = "array_any_all")]
-use crate::prelude::array::any_all::{array_all, array_any};
+use crate::prelude::array::any_all::{array_all, array_all_kleene, array_any, array_any_kleene};
 use crate::prelude::array::get::array_get;
 use crate::prelude::array::join::array_join;
 use crate::prelude::array::sum_mean::sum_array_numerical;
-use crate::series::ArgAgg;
+#[cfg(feature = "diff")]
+use crate::prelude::diff;
+use crate::series::{ArgAgg, clip, clip_max, clip_min};
+#[cfg(feature = "interpolate")]
+use crate::series::{InterpolationMethod, interpolate};
+#[cfg(feature = "cum_agg")]
+use crate::series::{cum_prod, cum_sum};
 
 pub fn has_inner_nulls(ca: &ArrayChunked) -> bool {
     for arr in ca.downcast_iter() {
@@ -26,10 +41,186 @@ pub fn has_inner_nulls(ca: &ArrayChunked) -> bool {
     false
 }
 
+/// Min/max of every row. For booleans, the min is whether all elements are `true` and the max
+/// is whether any element is `true`. Null elements are skipped.
 fn get_agg(ca: &ArrayChunked, agg_type: AggType) -> Series {
-    let values = ca.get_inner();
     let width = ca.width();
-    min_max::array_dispatch(ca.name().clone(), &values, width, agg_type)
+    if width == 0 {
+        return Series::full_null(ca.name().clone(), ca.len(), ca.inner_dtype());
+    }
+
+    if ca.inner_dtype() == &DataType::Boolean {
+        let out: BooleanChunked = ca
+            .apply_amortized_generic(|opt_s| {
+                let s = opt_s?;
+                let ca = s.as_ref().bool().unwrap();
+                match agg_type {
+                    AggType::Min => ca.min(),
+                    AggType::Max => ca.max(),
+                }
+            })
+            .with_name(ca.name().clone());
+        return out.into_series();
+    }
+
+    let ca = ca.rechunk();
+    let values = ca.get_inner();
+    let validity = ca.rechunk_validity();
+    min_max::array_dispatch(
+        ca.name().clone(),
+        &values,
+        width,
+        validity.as_ref(),
+        agg_type,
+    )
+}
+
+/// Apply `f` to every non-null row of `ca`. `f` may change the inner dtype, but must return
+/// `width` elements.
+fn apply_rows_with_width<F>(ca: &ArrayChunked, width: usize, mut f: F) -> PolarsResult<ArrayChunked>
+where
+    F: FnMut(&Series) -> PolarsResult<Series>,
+{
+    let out = ca.try_apply_amortized_to_list(|s| f(s.as_ref()))?;
+    let dtype = DataType::Array(Box::new(out.inner_dtype().clone()), width);
+    Ok(out.cast(&dtype)?.array()?.clone())
+}
+
+/// Apply `op` to the inner values of two arrays of the same width and length, giving an array of
+/// the same width. A null row in either input gives a null row.
+fn zip_inner_with<F>(
+    ca: &ArrayChunked,
+    other: &ArrayChunked,
+    op_name: &str,
+    op: F,
+) -> PolarsResult<ArrayChunked>
+where
+    F: FnOnce(&Series, &Series) -> PolarsResult<Series>,
+{
+    polars_ensure!(
+        ca.width() == other.width(),
+        ShapeMismatch: "{}: column '{}' has width {}, but column '{}' has width {}",
+        op_name, ca.name(), ca.width(), other.name(), other.width(),
+    );
+    polars_ensure!(
+        ca.len() == other.len(),
+        length_mismatch = op_name,
+        ca.len(),
+        other.len()
+    );
+
+    let lhs = ca.rechunk();
+    let rhs = other.rechunk();
+    let values = op(&lhs.get_inner(), &rhs.get_inner())?.rechunk();
+    let values = values.chunks()[0].clone();
+    let validity = combine_validities_and(
+        lhs.rechunk_validity().as_ref(),
+        rhs.rechunk_validity().as_ref(),
+    );
+    let arr = FixedSizeListArray::new(
+        FixedSizeListArray::default_datatype(values.dtype().clone(), ca.width()),
+        ca.len(),
+        values,
+        validity,
+    );
+    Ok(ArrayChunked::with_chunk(ca.name().clone(), arr))
+}
+
+/// Trailing rolling mean over `s`, skipping null elements. The first `window - 1` positions and
+/// windows with only nulls are null.
+fn rolling_mean_row(s: &Series, window: usize) -> PolarsResult<Series> {
+    let s = s.cast(&DataType::Float64)?;
+    let values = s.f64()?.iter().collect::<Vec<_>>();
+    let out: Float64Chunked = (0..values.len())
+        .map(|i| {
+            if i + 1 < window {
+                return None;
+            }
+            let (sum, count) = values[i + 1 - window..=i]
+                .iter()
+                .flatten()
+                .fold((0.0, 0), |(sum, count), v| (sum + v, count + 1));
+            (count > 0).then(|| sum / count as f64)
+        })
+        .collect();
+    Ok(out.into_series())
+}
+
+/// What [`ArrayNameSpace::array_shift_with_fill`] puts in the positions vacated by a shift.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ShiftFill {
+    /// Vacated positions become null.
+    #[default]
+    Null,
+    /// Elements shifted out on one side come back in on the other.
+    Cyclic,
+}
+
+/// Rotate the elements of `s` by `n` positions to the right, or to the left if `n` is negative.
+fn rotate(s: &Series, n: i64) -> Series {
+    let len = s.len();
+    if len == 0 {
+        return s.clone();
+    }
+    let n = n.rem_euclid(len as i64) as usize;
+    let mut out = s.slice((len - n) as i64, n);
+    out.append(&s.slice(0, len - n)).unwrap();
+    out
+}
+
+/// Which norm [`ArrayNameSpace::array_norm`] computes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NormOrd {
+    /// Sum of the absolute values.
+    L1,
+    /// Square root of the sum of squares.
+    #[default]
+    L2,
+    /// Largest absolute value.
+    LInf,
+}
+
+/// Norm of the non-null elements of `ca`, or `None` if all elements are null.
+fn norm_row(ca: &Float64Chunked, ord: NormOrd) -> Option<f64> {
+    let mut values = ca.iter().flatten().map(f64::abs).peekable();
+    values.peek()?;
+    Some(match ord {
+        NormOrd::L1 => values.sum(),
+        NormOrd::L2 => values.map(|v| v * v).sum::<f64>().sqrt(),
+        NormOrd::LInf => values.fold(0.0, f64::max),
+    })
+}
+
+/// Where [`ArrayNameSpace::array_pad`] adds the fill elements.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PadSide {
+    /// Before the existing elements.
+    Start,
+    /// After the existing elements.
+    #[default]
+    End,
+}
+
+/// The elementwise operation [`ArrayNameSpace::array_zip_with`] applies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ArrZipOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Min,
+    Max,
+}
+
+/// Elementwise minimum or maximum of `lhs` and `rhs`, which is null if either side is null.
+fn min_max_kleene(lhs: &Series, rhs: &Series, min: bool) -> PolarsResult<Series> {
+    let dtype = try_get_supertype(lhs.dtype(), rhs.dtype())?;
+    let lhs = lhs.cast(&dtype)?;
+    let rhs = rhs.cast(&dtype)?;
+    let take_lhs = if min { lhs.lt(&rhs)? } else { lhs.gt(&rhs)? };
+    let out = lhs.zip_with(&take_lhs, &rhs)?;
+    let nulls = Series::full_null(PlSmallStr::EMPTY, out.len(), out.dtype());
+    out.zip_with(&take_lhs.is_not_null(), &nulls)
 }
 
 pub trait ArrayNameSpace: AsArray {
@@ -57,6 +248,22 @@ pub trait ArrayNameSpace: AsArray {
         }
     }
 
+    /// Same as [`ArrayNameSpace::array_sum`], but rows with only null elements give null instead
+    /// of 0.
+    fn array_sum_null_if_all_null(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        let out = ca.array_sum()?;
+        if !has_inner_nulls(ca) {
+            return Ok(out);
+        }
+
+        let has_valid: BooleanChunked = ca.apply_amortized_generic(|opt_s| {
+            opt_s.map(|s| s.as_ref().null_count() < s.as_ref().len())
+        });
+        let nulls = Series::full_null(PlSmallStr::EMPTY, out.len(), out.dtype());
+        out.zip_with(&has_valid, &nulls)
+    }
+
     fn array_mean(&self) -> PolarsResult<Series> {
         let ca = self.as_array();
         dispersion::mean_with_nulls(ca)
@@ -67,6 +274,13 @@ pub trait ArrayNameSpace: AsArray {
         dispersion::median_with_nulls(ca)
     }
 
+    /// The `quantile` of the non-null elements of every row, interpolated as given by `method`.
+    /// Rows without non-null elements give null.
+    fn array_quantile(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        dispersion::quantile_with_nulls(ca, quantile, method)
+    }
+
     fn array_std(&self, ddof: u8) -> PolarsResult<Series> {
         let ca = self.as_array();
         dispersion::std_with_nulls(ca, ddof)
@@ -88,9 +302,22 @@ pub trait ArrayNameSpace: AsArray {
     }
 
     fn array_n_unique(&self) -> PolarsResult<IdxCa> {
+        self.array_n_unique_with_nulls(true)
+    }
+
+    /// Count the distinct elements per row. A null element only counts as a distinct value if
+    /// `include_nulls` is set.
+    fn array_n_unique_with_nulls(&self, include_nulls: bool) -> PolarsResult<IdxCa> {
         let ca = self.as_array();
         ca.try_apply_amortized_generic(|opt_s| {
-            let opt_v = opt_s.map(|s| s.as_ref().n_unique()).transpose()?;
+            let opt_v = opt_s
+                .map(|s| {
+                    let s = s.as_ref();
+                    let n_unique = s.n_unique()?;
+                    let has_nulls = s.has_nulls();
+                    PolarsResult::Ok(n_unique - (!include_nulls && has_nulls) as usize)
+                })
+                .transpose()?;
             Ok(opt_v.map(|idx| idx as IdxSize))
         })
     }
@@ -107,6 +334,22 @@ pub trait ArrayNameSpace: AsArray {
         array_all(ca)
     }
 
+    /// Same as [`ArrayNameSpace::array_any`], but with Kleene logic: a row without true elements
+    /// but with null elements gives null.
+    #[cfg(feature = "array_any_all")]
+    fn array_any_kleene(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_any_kleene(ca)
+    }
+
+    /// Same as [`ArrayNameSpace::array_all`], but with Kleene logic: a row without false
+    /// elements but with null elements gives null.
+    #[cfg(feature = "array_any_all")]
+    fn array_all_kleene(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_all_kleene(ca)
+    }
+
     fn array_sort(&self, options: SortOptions) -> PolarsResult<ArrayChunked> {
         let ca = self.as_array();
         // SAFETY: Sort only changes the order of the elements in each subarray.
@@ -119,6 +362,8 @@ pub trait ArrayNameSpace: AsArray {
         unsafe { ca.apply_amortized_same_type(|s| s.as_ref().reverse()) }
     }
 
+    /// The index of the minimum within every row. Ties resolve to the first occurrence. Null
+    /// elements are ignored, so rows with only nulls give null.
     fn array_arg_min(&self) -> IdxCa {
         let ca = self.as_array();
         ca.apply_amortized_generic(|opt_s| {
@@ -126,6 +371,8 @@ pub trait ArrayNameSpace: AsArray {
         })
     }
 
+    /// The index of the maximum within every row. Ties resolve to the first occurrence. Null
+    /// elements are ignored, so rows with only nulls give null.
     fn array_arg_max(&self) -> IdxCa {
         let ca = self.as_array();
         ca.apply_amortized_generic(|opt_s| {
@@ -133,6 +380,112 @@ pub trait ArrayNameSpace: AsArray {
         })
     }
 
+    /// Convert to a list column without copying the elements. Null rows stay null.
+    fn array_to_list(&self) -> ListChunked {
+        let ca = self.as_array();
+        let width = ca.width();
+        let chunks = ca
+            .downcast_iter()
+            .map(|arr| {
+                let offsets = (0..=arr.len())
+                    .map(|i| (i * width) as i64)
+                    .collect::<Vec<_>>();
+                // SAFETY: the offsets are monotonically increasing.
+                let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+                ListArray::<i64>::new(
+                    ListArray::<i64>::default_datatype(arr.values().dtype().clone()),
+                    offsets,
+                    arr.values().clone(),
+                    arr.validity().cloned(),
+                )
+                .boxed()
+            })
+            .collect();
+        // SAFETY: the values have the physical dtype of the inner dtype.
+        unsafe {
+            ListChunked::from_chunks_and_dtype(
+                ca.name().clone(),
+                chunks,
+                DataType::List(Box::new(ca.inner_dtype().clone())),
+            )
+        }
+    }
+
+    /// Gather the elements at `indices` from every row, where `indices` is an integer array. The
+    /// output has the width of `indices`. Negative indices count from the end of the row. Out of
+    /// bounds indices give null if `null_on_oob` is set, and an error otherwise.
+    #[cfg(feature = "list_gather")]
+    fn array_take(&self, indices: &ArrayChunked, null_on_oob: bool) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        polars_ensure!(
+            indices.inner_dtype().is_integer(),
+            ComputeError: "cannot use dtype `{}` as an index", indices.inner_dtype()
+        );
+
+        let width = indices.width();
+        let list = ca.cast(&DataType::List(Box::new(ca.inner_dtype().clone())))?;
+        let indices = indices.cast(&DataType::List(Box::new(indices.inner_dtype().clone())))?;
+        let out = list.list()?.lst_gather(&indices, null_on_oob)?;
+        let dtype = DataType::Array(Box::new(ca.inner_dtype().clone()), width);
+        Ok(out.cast(&dtype)?.array()?.clone())
+    }
+
+    /// Replace the element at position `index` of every row with the value of `values` in that
+    /// row. `values` must have the length of the array or length 1, in which case it is broadcast.
+    /// Null rows stay null.
+    fn array_set(&self, index: usize, values: &Series) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        let width = ca.width();
+        polars_ensure!(
+            index < width,
+            OutOfBounds: "arr.set: index {} is out of bounds for arrays of width {}",
+            index, width,
+        );
+        polars_ensure!(
+            values.len() == ca.len() || values.len() == 1,
+            length_mismatch = "arr.set",
+            ca.len(),
+            values.len()
+        );
+
+        let values = values.strict_cast(ca.inner_dtype())?;
+        let values = values.to_physical_repr().rechunk();
+        let values = values.chunks()[0].as_ref();
+        let broadcast = values.len() == 1;
+
+        let ca = ca.rechunk();
+        let arr = ca.downcast_as_array();
+        let inner = arr.values().as_ref();
+        let mut builder = make_builder(inner.dtype());
+        builder.reserve(inner.len());
+        for row in 0..arr.len() {
+            let start = row * width;
+            builder.subslice_extend(inner, start, index, ShareStrategy::Always);
+            let value_idx = if broadcast { 0 } else { row };
+            builder.subslice_extend(values, value_idx, 1, ShareStrategy::Always);
+            builder.subslice_extend(
+                inner,
+                start + index + 1,
+                width - index - 1,
+                ShareStrategy::Always,
+            );
+        }
+        let out = FixedSizeListArray::new(
+            arr.dtype().clone(),
+            arr.len(),
+            builder.freeze_reset(),
+            arr.validity().cloned(),
+        );
+        // SAFETY: the values have the physical dtype of the inner dtype.
+        Ok(unsafe {
+            ArrayChunked::from_chunks_and_dtype(
+                ca.name().clone(),
+                vec![out.boxed()],
+                ca.dtype().clone(),
+            )
+        })
+    }
+
     fn array_get(&self, index: &Int64Chunked, null_on_oob: bool) -> PolarsResult<Series> {
         let ca = self.as_array();
         array_get(ca, index, null_on_oob)
@@ -149,8 +502,337 @@ pub trait ArrayNameSpace: AsArray {
         array_count_matches(ca, element)
     }
 
+    /// Whether each row contains `value`. The inner dtype must be castable to the dtype of
+    /// `value`. A null `value` checks for null elements.
+    fn array_contains(&self, value: AnyValue) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_array();
+        array_contains(ca, value)
+    }
+
+    /// Explode the array and return the index of the row every element came from next to the
+    /// exploded values.
+    ///
+    /// If `keep_nulls` is set, a null row explodes into a single null element, otherwise it is
+    /// dropped.
+    fn array_explode_with_index(&self, keep_nulls: bool) -> PolarsResult<(IdxCa, Series)> {
+        let ca = self.as_array();
+        let values = ca.explode(ExplodeOptions {
+            empty_as_null: false,
+            keep_nulls,
+        })?;
+
+        let width = ca.width();
+        let validity = ca.rechunk_validity();
+        let mut idx = Vec::with_capacity(values.len());
+        for i in 0..ca.len() {
+            if validity.as_ref().is_none_or(|v| v.get_bit(i)) {
+                idx.extend(std::iter::repeat_n(i as IdxSize, width));
+            } else if keep_nulls {
+                idx.push(i as IdxSize);
+            }
+        }
+        debug_assert_eq!(idx.len(), values.len());
+
+        let idx = IdxCa::from_vec(PlSmallStr::from_static("index"), idx);
+        Ok((idx, values))
+    }
+
+    /// Compute the difference of every element with the element `n` positions earlier in the same
+    /// row. With [`NullBehavior::Drop`] the first `n` positions are removed, so the output width is
+    /// reduced by `n`. Otherwise they are null.
+    #[cfg(feature = "diff")]
+    fn array_diff(&self, n: i64, null_behavior: NullBehavior) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        let width = match null_behavior {
+            NullBehavior::Ignore => ca.width(),
+            NullBehavior::Drop => {
+                polars_ensure!(
+                    n >= 0 && n as usize <= ca.width(),
+                    InvalidOperation: "arr.diff: cannot drop the first {} elements of arrays of \
+                    width {}",
+                    n, ca.width(),
+                );
+                ca.width() - n as usize
+            },
+        };
+        apply_rows_with_width(ca, width, |s| diff(s, n, null_behavior))
+    }
+
+    /// Compute the trailing mean over `window` elements within every row. The first `window - 1`
+    /// positions of every row are null. Null elements are skipped, so a window with only nulls
+    /// gives null.
+    fn array_rolling_mean(&self, window: usize) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        polars_ensure!(
+            ca.inner_dtype().is_primitive_numeric(),
+            InvalidOperation: "arr.rolling_mean: expected a numeric array, got {}", ca.dtype()
+        );
+        polars_ensure!(window > 0, ComputeError: "arr.rolling_mean: window must be positive");
+        apply_rows_with_width(ca, ca.width(), |s| rolling_mean_row(s, window))
+    }
+
+    /// Fill the null elements of every row by interpolating between the non-null elements around
+    /// them, treating each row as a short series. Leading and trailing nulls stay null.
+    #[cfg(feature = "interpolate")]
+    fn array_interpolate(&self, method: InterpolationMethod) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        apply_rows_with_width(ca, ca.width(), |s| Ok(interpolate(s, method)))
+    }
+
+    /// Compute the cumulative sum within every row. Null elements stay null and are skipped in the
+    /// accumulation. Small integer types are upcast like [`cum_sum`] does.
+    #[cfg(feature = "cum_agg")]
+    fn array_cum_sum(&self) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        apply_rows_with_width(ca, ca.width(), |s| cum_sum(s, false))
+    }
+
+    /// Compute the cumulative product within every row. Null elements stay null and are skipped
+    /// in the accumulation. Small integer types are upcast like [`cum_prod`] does.
+    #[cfg(feature = "cum_agg")]
+    fn array_cum_prod(&self) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        apply_rows_with_width(ca, ca.width(), |s| cum_prod(s, false))
+    }
+
+    /// Keep the elements of every row for which `mask` is true. `mask` must be a boolean array of
+    /// the same width. As the rows can end up with differing lengths, the result is a list.
+    ///
+    /// A null row in either input gives a null row. Null mask elements are treated as false.
+    fn array_filter(&self, mask: &ArrayChunked) -> PolarsResult<ListChunked> {
+        let ca = self.as_array();
+        polars_ensure!(
+            mask.inner_dtype() == &DataType::Boolean,
+            InvalidOperation: "arr.filter: expected a mask of dtype array[bool], got {}",
+            mask.dtype(),
+        );
+        polars_ensure!(
+            mask.width() == ca.width(),
+            ShapeMismatch: "arr.filter: mask has width {}, expected width {} of column '{}'",
+            mask.width(), ca.width(), ca.name(),
+        );
+        polars_ensure!(
+            ca.len() == mask.len(),
+            length_mismatch = "arr.filter",
+            ca.len(),
+            mask.len()
+        );
+
+        let mut builder = get_list_builder(
+            ca.inner_dtype(),
+            ca.get_inner().len(),
+            ca.len(),
+            ca.name().clone(),
+        );
+        for (opt_s, opt_mask) in ca.amortized_iter().zip(mask.amortized_iter()) {
+            match (opt_s, opt_mask) {
+                (Some(s), Some(mask)) => {
+                    let s = s.as_ref().filter(mask.as_ref().bool()?)?;
+                    builder.append_series(&s)?
+                },
+                _ => builder.append_null(),
+            }
+        }
+        Ok(builder.finish())
+    }
+
+    /// Compute the `ord` norm of every numeric row as a float. Null elements are skipped, and rows
+    /// with only null elements give null.
+    fn array_norm(&self, ord: NormOrd) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        polars_ensure!(
+            ca.inner_dtype().is_primitive_numeric(),
+            InvalidOperation: "arr.norm: expected a numeric array, got {}", ca.dtype(),
+        );
+        let ca = ca.array_cast(&DataType::Float64, false)?;
+        let out: Float64Chunked = ca.apply_amortized_generic(|opt_s| {
+            opt_s.and_then(|s| norm_row(s.as_ref().f64().unwrap(), ord))
+        });
+        Ok(out.with_name(ca.name().clone()).into_series())
+    }
+
+    /// The most frequent element of every row, skipping null elements. Ties are broken by taking
+    /// the smallest value. Rows with only null elements give null.
+    #[cfg(feature = "mode")]
+    fn array_mode(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        let modes = ca.try_apply_amortized_to_list(|s| mode(&s.as_ref().drop_nulls(), false))?;
+        modes.lst_min()
+    }
+
+    /// Compute the dot product of every row with the same row of `other`, which must be a numeric
+    /// array of the same width.
+    ///
+    /// Terms with a null element are skipped, unless `propagate_nulls` is set, in which case the
+    /// row becomes null. A null row in either input gives a null row.
+    fn array_dot(&self, other: &ArrayChunked, propagate_nulls: bool) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        polars_ensure!(
+            ca.inner_dtype().is_primitive_numeric() && other.inner_dtype().is_primitive_numeric(),
+            InvalidOperation: "arr.dot: expected numeric arrays, got {} and {}",
+            ca.dtype(), other.dtype(),
+        );
+        let products = zip_inner_with(ca, other, "arr.dot", |lhs, rhs| lhs * rhs)?;
+
+        let out = products.array_sum()?;
+        if propagate_nulls && has_inner_nulls(&products) {
+            let no_nulls: BooleanChunked =
+                products.apply_amortized_generic(|opt_s| opt_s.map(|s| !s.as_ref().has_nulls()));
+            let nulls = Series::full_null(PlSmallStr::EMPTY, out.len(), out.dtype());
+            return out.zip_with(&no_nulls, &nulls);
+        }
+        Ok(out)
+    }
+
+    /// Apply `op` to the elements of every row and the elements at the same positions in `other`,
+    /// which must be a numeric array of the same width. A null on either side gives null.
+    fn array_zip_with(&self, other: &ArrayChunked, op: ArrZipOp) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        polars_ensure!(
+            ca.inner_dtype().is_primitive_numeric() && other.inner_dtype().is_primitive_numeric(),
+            InvalidOperation: "arr.zip_with: expected numeric arrays, got {} and {}",
+            ca.dtype(), other.dtype(),
+        );
+        zip_inner_with(ca, other, "arr.zip_with", |lhs, rhs| match op {
+            ArrZipOp::Add => lhs + rhs,
+            ArrZipOp::Sub => lhs - rhs,
+            ArrZipOp::Mul => lhs * rhs,
+            ArrZipOp::Div => lhs / rhs,
+            ArrZipOp::Min => min_max_kleene(lhs, rhs, true),
+            ArrZipOp::Max => min_max_kleene(lhs, rhs, false),
+        })
+    }
+
+    /// Compare the elements of every row with the elements at the same positions in `other`, which
+    /// must have the same width.
+    ///
+    /// If `kleene` is set, comparing with a null element gives null, otherwise it gives false. A
+    /// null row in either input gives a null row.
+    fn array_eq(&self, other: &ArrayChunked, kleene: bool) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        zip_inner_with(ca, other, "arr.eq", |lhs, rhs| {
+            let eq = lhs.equal(rhs)?;
+            let eq = if kleene {
+                eq
+            } else {
+                eq.fill_null_with_values(false)?
+            };
+            Ok(eq.into_series())
+        })
+    }
+
+    /// Clamp every element of a numeric array into `[min, max]`, keeping the width and the row
+    /// validity. A missing bound leaves that side unclipped, and null elements stay null.
+    fn array_clip(
+        &self,
+        min: Option<AnyValue>,
+        max: Option<AnyValue>,
+    ) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        let bound =
+            |value: AnyValue| Series::new(PlSmallStr::EMPTY, [value]).strict_cast(ca.inner_dtype());
+        let min = min.map(bound).transpose()?;
+        let max = max.map(bound).transpose()?;
+
+        let ca = ca.rechunk();
+        let inner = ca.get_inner();
+        let values = match (&min, &max) {
+            (Some(min), Some(max)) => clip(&inner, min, max)?,
+            (Some(min), None) => clip_min(&inner, min)?,
+            (None, Some(max)) => clip_max(&inner, max)?,
+            (None, None) => return Ok(ca.into_owned()),
+        };
+        let values = values.rechunk().chunks()[0].clone();
+        let arr = FixedSizeListArray::new(
+            FixedSizeListArray::default_datatype(values.dtype().clone(), ca.width()),
+            ca.len(),
+            values,
+            ca.rechunk_validity(),
+        );
+        Ok(ArrayChunked::with_chunk(ca.name().clone(), arr))
+    }
+
+    /// Grow every row to `to_width` elements by adding copies of `fill` on the given `side`. Null
+    /// rows stay null.
+    fn array_pad(
+        &self,
+        to_width: usize,
+        side: PadSide,
+        fill: AnyValue,
+    ) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        let width = ca.width();
+        polars_ensure!(
+            to_width >= width,
+            InvalidOperation: "arr.pad: cannot pad arrays of width {} to the smaller width {}",
+            width, to_width,
+        );
+        let pad = to_width - width;
+
+        let fill = Series::new(PlSmallStr::EMPTY, [fill]).strict_cast(ca.inner_dtype())?;
+        let fill = fill.new_from_index(0, pad).to_physical_repr().rechunk();
+        let fill = fill.chunks()[0].as_ref();
+
+        let ca = ca.rechunk();
+        let arr = ca.downcast_as_array();
+        let inner = arr.values().as_ref();
+        let mut builder = make_builder(inner.dtype());
+        builder.reserve(arr.len() * to_width);
+        for row in 0..arr.len() {
+            if side == PadSide::Start {
+                builder.subslice_extend(fill, 0, pad, ShareStrategy::Always);
+            }
+            builder.subslice_extend(inner, row * width, width, ShareStrategy::Always);
+            if side == PadSide::End {
+                builder.subslice_extend(fill, 0, pad, ShareStrategy::Always);
+            }
+        }
+        let values = builder.freeze_reset();
+        let out = FixedSizeListArray::new(
+            FixedSizeListArray::default_datatype(values.dtype().clone(), to_width),
+            arr.len(),
+            values,
+            arr.validity().cloned(),
+        );
+        // SAFETY: the values have the physical dtype of the inner dtype.
+        Ok(unsafe {
+            ArrayChunked::from_chunks_and_dtype(
+                ca.name().clone(),
+                vec![out.boxed()],
+                DataType::Array(Box::new(ca.inner_dtype().clone()), to_width),
+            )
+        })
+    }
+
+    /// Cast the elements to `inner_dtype`, keeping the width and the row validity. With `strict`
+    /// set, elements that cannot be cast are an error instead of becoming null.
+    fn array_cast(&self, inner_dtype: &DataType, strict: bool) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        let options = if strict {
+            CastOptions::Strict
+        } else {
+            CastOptions::NonStrict
+        };
+        let dtype = DataType::Array(Box::new(inner_dtype.clone()), ca.width());
+        let out = ca
+            .clone()
+            .into_series()
+            .cast_with_options(&dtype, options)?;
+        Ok(out.array()?.clone())
+    }
+
     fn array_shift(&self, n: &Series) -> PolarsResult<Series> {
+        self.array_shift_with_fill(n, ShiftFill::Null)
+    }
+
+    /// Shift the elements within every row by `n` positions, filling the vacated positions as
+    /// given by `fill`. Negative `n` shifts to the left.
+    fn array_shift_with_fill(&self, n: &Series, fill: ShiftFill) -> PolarsResult<Series> {
         let ca = self.as_array();
+        let shift = |s: &Series, n: i64| match fill {
+            ShiftFill::Null => s.shift(n),
+            ShiftFill::Cyclic => rotate(s, n),
+        };
         let n_s = n.cast(&DataType::Int64)?;
         let n = n_s.i64()?;
         let out = match (ca.len(), n.len()) {
@@ -159,7 +841,7 @@ pub trait ArrayNameSpace: AsArray {
                 unsafe {
                     ca.zip_and_apply_amortized_same_type(n, |opt_s, opt_periods| {
                         match (opt_s, opt_periods) {
-                            (Some(s), Some(n)) => Some(s.as_ref().shift(n)),
+                            (Some(s), Some(n)) => Some(shift(s.as_ref(), n)),
                             _ => None,
                         }
                     })
@@ -168,7 +850,7 @@ pub trait ArrayNameSpace: AsArray {
             (_, 1) => {
                 if let Some(n) = n.get(0) {
                     // SAFETY: Shift does not change the dtype and number of elements of sub-array.
-                    unsafe { ca.apply_amortized_same_type(|s| s.as_ref().shift(n)) }
+                    unsafe { ca.apply_amortized_same_type(|s| shift(s.as_ref(), n)) }
                 } else {
                     ArrayChunked::full_null_with_dtype(
                         ca.name().clone(),
@@ -186,7 +868,7 @@ pub trait ArrayNameSpace: AsArray {
                     unsafe {
                         ca.zip_and_apply_amortized_same_type(n, |opt_s, opt_periods| {
                             match (opt_s, opt_periods) {
-                                (Some(s), Some(n)) => Some(s.as_ref().shift(n)),
+                                (Some(s), Some(n)) => Some(shift(s.as_ref(), n)),
                                 _ => None,
                             }
                         })
@@ -205,7 +887,70 @@ pub trait ArrayNameSpace: AsArray {
         Ok(out.into_series())
     }
 
-    fn array_slice(&self, offset: i64, length: i64) -> PolarsResult<Series> {
+    /// Slice every row with its own `offset` and `length`, taken from the same row of `offsets` and
+    /// `lengths`, or broadcast if they have a single value. Negative offsets count from the end of
+    /// the row.
+    ///
+    /// Windows that do not lie within the row are clamped to it, unless `null_on_oob` is set, in
+    /// which case they give a null row. A null row, offset or length gives a null row.
+    fn array_slice_each(
+        &self,
+        offsets: &Series,
+        lengths: &Series,
+        null_on_oob: bool,
+    ) -> PolarsResult<ListChunked> {
+        let ca = self.as_array();
+        for other in [offsets, lengths] {
+            polars_ensure!(
+                other.len() == ca.len() || other.len() == 1,
+                length_mismatch = "arr.slice_each",
+                ca.len(),
+                other.len()
+            );
+        }
+        let offsets = offsets.strict_cast(&DataType::Int64)?;
+        let offsets = offsets.i64()?;
+        let lengths = lengths.strict_cast(&DataType::Int64)?;
+        let lengths = lengths.i64()?;
+        polars_ensure!(
+            lengths.min().is_none_or(|min| min >= 0),
+            InvalidOperation: "arr.slice_each: lengths must not be negative",
+        );
+
+        let width = ca.width() as i64;
+        let get = |ca: &Int64Chunked, i: usize| ca.get(if ca.len() == 1 { 0 } else { i });
+        let mut builder = get_list_builder(
+            ca.inner_dtype(),
+            ca.get_inner().len(),
+            ca.len(),
+            ca.name().clone(),
+        );
+        for (i, opt_s) in ca.amortized_iter().enumerate() {
+            match (opt_s, get(offsets, i), get(lengths, i)) {
+                (Some(s), Some(offset), Some(length)) => {
+                    let start = if offset < 0 {
+                        offset.saturating_add(width)
+                    } else {
+                        offset
+                    };
+                    if null_on_oob && (start < 0 || start.saturating_add(length) > width) {
+                        builder.append_null();
+                        continue;
+                    }
+                    builder.append_series(&s.as_ref().slice(offset, length as usize))?
+                },
+                _ => builder.append_null(),
+            }
+        }
+        Ok(builder.finish())
+    }
+
+    /// Slice every row at the same `offset` and `length`. Negative offsets count from the end of
+    /// the row and a negative length leaves that many elements off the end of the row.
+    ///
+    /// Windows that do not lie within the width of the array are clamped to it, unless
+    /// `null_on_oob` is set, in which case every row becomes a null row of width `length`.
+    fn array_slice(&self, offset: i64, length: i64, null_on_oob: bool) -> PolarsResult<Series> {
         let slice_arr: ArrayChunked = unary_kernel(
             self.as_array(),
             move |arr: &FixedSizeListArray| -> FixedSizeListArray {
@@ -216,6 +961,22 @@ pub trait ArrayNameSpace: AsArray {
                 }
                 .try_into()
                 .expect("Length can not be larger than i64::MAX");
+                let inner = match arr.dtype() {
+                    ArrowDataType::FixedSizeList(inner, _) => inner,
+                    _ => unreachable!(),
+                };
+
+                let width = arr.size() as i64;
+                let start = if offset < 0 {
+                    offset.saturating_add(width)
+                } else {
+                    offset
+                };
+                if null_on_oob && (start < 0 || start.saturating_add(length as i64) > width) {
+                    let sliced_dtype = ArrowDataType::FixedSizeList(inner.clone(), length);
+                    return FixedSizeListArray::new_null(sliced_dtype, arr.len());
+                }
+
                 let (raw_offset, slice_len) = slice_offsets(offset, length, arr.size());
 
                 let mut builder = make_builder(arr.values().dtype());
@@ -226,6 +987,8 @@ pub trait ArrayNameSpace: AsArray {
                 let values = arr.values().as_ref();
                 for row in 0..arr.len() {
                     if !arr.is_valid(row) {
+                        // null rows still occupy `slice_len` slots in the values
+                        builder.extend_nulls(slice_len);
                         validity.push(false);
                         continue;
                     }
@@ -234,12 +997,7 @@ pub trait ArrayNameSpace: AsArray {
                     validity.push(true);
                 }
                 let values = builder.freeze_reset();
-                let sliced_dtype = match arr.dtype() {
-                    ArrowDataType::FixedSizeList(inner, _) => {
-                        ArrowDataType::FixedSizeList(inner.clone(), slice_len)
-                    },
-                    _ => unreachable!(),
-                };
+                let sliced_dtype = ArrowDataType::FixedSizeList(inner.clone(), slice_len);
                 FixedSizeListArray::new(
                     sliced_dtype,
                     arr.len(),
@@ -253,3 +1011,1211 @@ pub trait ArrayNameSpace: AsArray {
 }
 
 impl ArrayNameSpace for ArrayChunked {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Build an `Int32` array column from rows of `i32` or `Option<i32>` elements.
+    fn int_arrays<T>(name: &str, rows: Vec<Option<Vec<T>>>, width: usize) -> ArrayChunked
+    where
+        Series: NamedFrom<Vec<T>, [T]>,
+    {
+        let rows = rows
+            .into_iter()
+            .map(|opt_row| opt_row.map(|row| Series::new(PlSmallStr::EMPTY, row)))
+            .collect::<Vec<_>>();
+        Series::new(name.into(), rows)
+            .cast(&DataType::Array(Box::new(DataType::Int32), width))
+            .unwrap()
+            .array()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn test_array_slice() -> PolarsResult<()> {
+        let ca = int_arrays(
+            "a",
+            vec![Some(vec![1, 2, 3, 4]), None, Some(vec![5, 6, 7, 8])],
+            4,
+        );
+
+        let out = ca.array_slice(1, 2, false)?;
+        let expected = int_arrays("a", vec![Some(vec![2, 3]), None, Some(vec![6, 7])], 2);
+        assert!(out.equals_missing(&expected.into_series()));
+
+        let out = ca.array_slice(-3, 2, false)?;
+        let expected = int_arrays("a", vec![Some(vec![2, 3]), None, Some(vec![6, 7])], 2);
+        assert!(out.equals_missing(&expected.into_series()));
+
+        // windows running past the end are clamped to the width of the array
+        let out = ca.array_slice(3, 5, false)?;
+        let expected = int_arrays("a", vec![Some(vec![4]), None, Some(vec![8])], 1);
+        assert!(out.equals_missing(&expected.into_series()));
+
+        // unless they should become null
+        let out = ca.array_slice(3, 5, true)?;
+        let expected = Series::full_null(
+            "a".into(),
+            3,
+            &DataType::Array(Box::new(DataType::Int32), 5),
+        );
+        assert!(out.equals_missing(&expected));
+
+        // the same holds for windows starting before the row
+        let out = ca.array_slice(-6, 3, false)?;
+        let expected = int_arrays("a", vec![Some(vec![1]), None, Some(vec![5])], 1);
+        assert!(out.equals_missing(&expected.into_series()));
+
+        let out = ca.array_slice(-6, 3, true)?;
+        let expected = Series::full_null(
+            "a".into(),
+            3,
+            &DataType::Array(Box::new(DataType::Int32), 3),
+        );
+        assert!(out.equals_missing(&expected));
+
+        // windows within the row are not affected
+        let out = ca.array_slice(-2, 2, true)?;
+        let expected = int_arrays("a", vec![Some(vec![3, 4]), None, Some(vec![7, 8])], 2);
+        assert!(out.equals_missing(&expected.into_series()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_get() -> PolarsResult<()> {
+        let ca = int_arrays("a", vec![Some(vec![1, 2, 3]), None, Some(vec![4, 5, 6])], 3);
+        let get = |idx: i64, null_on_oob: bool| {
+            ca.array_get(&Int64Chunked::from_slice("idx".into(), &[idx]), null_on_oob)
+        };
+
+        let expected = Series::new("a".into(), [Some(1i32), None, Some(4)]);
+        assert!(get(0, false)?.equals_missing(&expected));
+
+        let expected = Series::new("a".into(), [Some(3i32), None, Some(6)]);
+        assert!(get(-1, false)?.equals_missing(&expected));
+
+        let expected = Series::new("a".into(), [None::<i32>, None, None]);
+        assert!(get(3, true)?.equals_missing(&expected));
+        assert!(get(-4, true)?.equals_missing(&expected));
+        assert!(get(3, false).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_sum_mean() -> PolarsResult<()> {
+        let ints = int_arrays(
+            "a",
+            vec![
+                Some(vec![Some(1), Some(2), None]),
+                None,
+                Some(vec![None, None, None]),
+            ],
+            3,
+        );
+
+        // like `Series::sum`, a row of only null elements sums to 0
+        let expected = Series::new("a".into(), [Some(3i32), None, Some(0)]);
+        assert!(ints.array_sum()?.equals_missing(&expected));
+        let expected = Series::new("a".into(), [Some(3i32), None, None]);
+        assert!(ints.array_sum_null_if_all_null()?.equals_missing(&expected));
+        let expected = Series::new("a".into(), [Some(1.5f64), None, None]);
+        assert!(ints.array_mean()?.equals_missing(&expected));
+
+        let floats = ints.cast(&DataType::Array(Box::new(DataType::Float64), 3))?;
+        let floats = floats.array()?;
+        let expected = Series::new("a".into(), [Some(3.0f64), None, Some(0.0)]);
+        assert!(floats.array_sum()?.equals_missing(&expected));
+        let expected = Series::new("a".into(), [Some(3.0f64), None, None]);
+        assert!(
+            floats
+                .array_sum_null_if_all_null()?
+                .equals_missing(&expected)
+        );
+        let expected = Series::new("a".into(), [Some(1.5f64), None, None]);
+        assert!(floats.array_mean()?.equals_missing(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_reverse() -> PolarsResult<()> {
+        let ca = int_arrays(
+            "a",
+            vec![
+                Some(vec![Some(1), None, Some(3), Some(4)]),
+                None,
+                Some(vec![None, None, Some(7), Some(8)]),
+            ],
+            4,
+        );
+        let expected = int_arrays(
+            "a",
+            vec![
+                Some(vec![Some(4), Some(3), None, Some(1)]),
+                None,
+                Some(vec![Some(8), Some(7), None, None]),
+            ],
+            4,
+        );
+
+        let out = ca.array_reverse();
+        assert_eq!(out.width(), 4);
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_contains() -> PolarsResult<()> {
+        let rows = vec![
+            Some(Series::new(PlSmallStr::EMPTY, [Some(1i32), Some(2)])),
+            Some(Series::new(PlSmallStr::EMPTY, [Some(3i32), None])),
+            None,
+        ];
+        let s =
+            Series::new("a".into(), rows).cast(&DataType::Array(Box::new(DataType::Int32), 2))?;
+        let ca = s.array()?;
+
+        let out = ca.array_contains(AnyValue::Int32(2))?;
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            [Some(true), Some(false), None]
+        );
+
+        // the value is cast to the inner dtype
+        let out = ca.array_contains(AnyValue::Int64(3))?;
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            [Some(false), Some(true), None]
+        );
+
+        let out = ca.array_contains(AnyValue::Null)?;
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            [Some(false), Some(true), None]
+        );
+
+        assert!(ca.array_contains(AnyValue::String("x")).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_explode_with_index() -> PolarsResult<()> {
+        let ca = int_arrays("a", vec![Some(vec![1, 2, 3]), None, Some(vec![4, 5, 6])], 3);
+
+        let (idx, values) = ca.array_explode_with_index(true)?;
+        assert_eq!(
+            idx.into_no_null_iter().collect::<Vec<_>>(),
+            [0, 0, 0, 1, 2, 2, 2]
+        );
+        let expected = Series::new(
+            "a".into(),
+            [
+                Some(1i32),
+                Some(2),
+                Some(3),
+                None,
+                Some(4),
+                Some(5),
+                Some(6),
+            ],
+        );
+        assert!(values.equals_missing(&expected));
+
+        let (idx, values) = ca.array_explode_with_index(false)?;
+        assert_eq!(
+            idx.into_no_null_iter().collect::<Vec<_>>(),
+            [0, 0, 0, 2, 2, 2]
+        );
+        let expected = Series::new("a".into(), [1i32, 2, 3, 4, 5, 6]);
+        assert!(values.equals(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_sort() -> PolarsResult<()> {
+        let ca = int_arrays(
+            "a",
+            vec![
+                Some(vec![Some(3), Some(1), Some(2)]),
+                Some(vec![Some(2), None, Some(1)]),
+                None,
+            ],
+            3,
+        );
+
+        let out = ca.array_sort(SortOptions::default())?;
+        let expected = int_arrays(
+            "a",
+            vec![
+                Some(vec![Some(1), Some(2), Some(3)]),
+                Some(vec![None, Some(1), Some(2)]),
+                None,
+            ],
+            3,
+        );
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        let options = SortOptions::default()
+            .with_order_descending(true)
+            .with_nulls_last(true);
+        let out = ca.array_sort(options)?;
+        let expected = int_arrays(
+            "a",
+            vec![
+                Some(vec![Some(3), Some(2), Some(1)]),
+                Some(vec![Some(2), Some(1), None]),
+                None,
+            ],
+            3,
+        );
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_min_max() -> PolarsResult<()> {
+        let rows = vec![
+            Some(Series::new(PlSmallStr::EMPTY, [Some(3i32), None, Some(1)])),
+            None,
+            Some(Series::new(PlSmallStr::EMPTY, [None::<i32>, None, None])),
+        ];
+        let s =
+            Series::new("a".into(), rows).cast(&DataType::Array(Box::new(DataType::Int32), 3))?;
+        let ca = s.array()?;
+        let expected = Series::new("a".into(), [Some(1i32), None, None]);
+        assert!(ca.array_min().equals_missing(&expected));
+        let expected = Series::new("a".into(), [Some(3i32), None, None]);
+        assert!(ca.array_max().equals_missing(&expected));
+
+        let rows = vec![
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(true), None, Some(false)],
+            )),
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(true), Some(true), None],
+            )),
+            None,
+            Some(Series::new(PlSmallStr::EMPTY, [None::<bool>, None, None])),
+        ];
+        let s =
+            Series::new("a".into(), rows).cast(&DataType::Array(Box::new(DataType::Boolean), 3))?;
+        let ca = s.array()?;
+        let expected = Series::new("a".into(), [Some(false), Some(true), None, None]);
+        assert!(ca.array_min().equals_missing(&expected));
+        let expected = Series::new("a".into(), [Some(true), Some(true), None, None]);
+        assert!(ca.array_max().equals_missing(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_n_unique() -> PolarsResult<()> {
+        let rows = [
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(1i32), Some(1), Some(1)],
+            )),
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(1i32), Some(2), Some(3)],
+            )),
+            Some(Series::new(PlSmallStr::EMPTY, [Some(1i32), None, None])),
+            None,
+        ];
+        let s =
+            Series::new("a".into(), rows).cast(&DataType::Array(Box::new(DataType::Int32), 3))?;
+        let ca = s.array()?;
+
+        let out = ca.array_n_unique()?;
+        let expected = [Some(1 as IdxSize), Some(3), Some(2), None];
+        assert_eq!(Vec::from(&out), expected);
+
+        let out = ca.array_n_unique_with_nulls(false)?;
+        let expected = [Some(1 as IdxSize), Some(3), Some(1), None];
+        assert_eq!(Vec::from(&out), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_shift_with_fill() -> PolarsResult<()> {
+        let ca = int_arrays("a", vec![Some(vec![1, 2, 3, 4, 5]), None], 5);
+        let arrays = |row: [Option<i32>; 5]| {
+            int_arrays("a", vec![Some(row.to_vec()), None], 5).into_series()
+        };
+
+        let n = Series::new("n".into(), [2i64]);
+        let out = ca.array_shift_with_fill(&n, ShiftFill::Null)?;
+        let expected = arrays([None, None, Some(1), Some(2), Some(3)]);
+        assert!(out.equals_missing(&expected));
+        let out = ca.array_shift_with_fill(&n, ShiftFill::Cyclic)?;
+        let expected = arrays([Some(4), Some(5), Some(1), Some(2), Some(3)]);
+        assert!(out.equals_missing(&expected));
+
+        let n = Series::new("n".into(), [-1i64]);
+        let out = ca.array_shift_with_fill(&n, ShiftFill::Null)?;
+        let expected = arrays([Some(2), Some(3), Some(4), Some(5), None]);
+        assert!(out.equals_missing(&expected));
+        let out = ca.array_shift_with_fill(&n, ShiftFill::Cyclic)?;
+        let expected = arrays([Some(2), Some(3), Some(4), Some(5), Some(1)]);
+        assert!(out.equals_missing(&expected));
+
+        // shifting by more than the width wraps around
+        let n = Series::new("n".into(), [-6i64]);
+        let out = ca.array_shift_with_fill(&n, ShiftFill::Cyclic)?;
+        assert!(out.equals_missing(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "diff")]
+    fn test_array_diff() -> PolarsResult<()> {
+        let ca = int_arrays("a", vec![Some(vec![1, 3, 6, 10]), None], 4);
+        let arrays = |row: Vec<Option<i32>>, width| {
+            int_arrays("a", vec![Some(row), None], width).into_series()
+        };
+
+        let out = ca.array_diff(1, NullBehavior::Ignore)?.into_series();
+        let expected = arrays(vec![None, Some(2), Some(3), Some(4)], 4);
+        assert!(out.equals_missing(&expected));
+
+        let out = ca.array_diff(2, NullBehavior::Ignore)?.into_series();
+        let expected = arrays(vec![None, None, Some(5), Some(7)], 4);
+        assert!(out.equals_missing(&expected));
+
+        let out = ca.array_diff(2, NullBehavior::Drop)?.into_series();
+        let expected = arrays(vec![Some(5), Some(7)], 2);
+        assert!(out.equals_missing(&expected));
+
+        assert!(ca.array_diff(5, NullBehavior::Drop).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_filter() -> PolarsResult<()> {
+        let ca = int_arrays(
+            "a",
+            vec![
+                Some(vec![1, 2, 3, 4]),
+                Some(vec![5, 6, 7, 8]),
+                Some(vec![9, 10, 11, 12]),
+                None,
+            ],
+            4,
+        );
+        let mask = Series::new(
+            "mask".into(),
+            [
+                Some(Series::new(PlSmallStr::EMPTY, [false, false, false, false])),
+                Some(Series::new(PlSmallStr::EMPTY, [true, false, true, false])),
+                Some(Series::new(PlSmallStr::EMPTY, [true, true, true, true])),
+                Some(Series::new(PlSmallStr::EMPTY, [true, true, true, true])),
+            ],
+        )
+        .cast(&DataType::Array(Box::new(DataType::Boolean), 4))?;
+
+        let out = ca.array_filter(mask.array()?)?;
+        let expected = Series::new(
+            "a".into(),
+            [
+                Some(Series::new(PlSmallStr::EMPTY, Vec::<i32>::new())),
+                Some(Series::new(PlSmallStr::EMPTY, [5i32, 7])),
+                Some(Series::new(PlSmallStr::EMPTY, [9i32, 10, 11, 12])),
+                None,
+            ],
+        );
+        assert!(out.into_series().equals_missing(&expected));
+
+        let narrow = int_arrays("b", vec![Some(vec![1, 2]); 4], 2);
+        let narrow = narrow.cast(&DataType::Array(Box::new(DataType::Boolean), 2))?;
+        let err = ca.array_filter(narrow.array()?).unwrap_err();
+        assert!(err.to_string().contains("mask has width 2"));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "cum_agg")]
+    fn test_array_cum_sum_prod() -> PolarsResult<()> {
+        let ca = int_arrays(
+            "a",
+            vec![Some(vec![Some(1), None, Some(3), Some(4)]), None],
+            4,
+        );
+
+        let out = ca.array_cum_sum()?.into_series();
+        let expected = int_arrays(
+            "a",
+            vec![Some(vec![Some(1), None, Some(4), Some(8)]), None],
+            4,
+        );
+        assert!(out.equals_missing(&expected.into_series()));
+
+        let out = ca.array_cum_prod()?.into_series();
+        let expected = int_arrays(
+            "a",
+            vec![Some(vec![Some(1), None, Some(3), Some(12)]), None],
+            4,
+        )
+        .cast(&DataType::Array(Box::new(DataType::Int64), 4))?;
+        assert!(out.equals_missing(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_dot() -> PolarsResult<()> {
+        let a = int_arrays("a", vec![Some(vec![1, 2, 3]), Some(vec![4, 5, 6]), None], 3);
+        let rows = [
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(1i32), Some(1), Some(1)],
+            )),
+            Some(Series::new(PlSmallStr::EMPTY, [Some(2i32), None, Some(1)])),
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(1i32), Some(1), Some(1)],
+            )),
+        ];
+        let b =
+            Series::new("b".into(), rows).cast(&DataType::Array(Box::new(DataType::Int32), 3))?;
+        let b = b.array()?;
+
+        let out = a.array_dot(b, false)?;
+        assert_eq!(Vec::from(out.i32()?), [Some(6), Some(14), None]);
+
+        let out = a.array_dot(b, true)?;
+        assert_eq!(Vec::from(out.i32()?), [Some(6), None, None]);
+
+        let narrow = int_arrays("c", vec![Some(vec![1, 2]); 3], 2);
+        let err = a.array_dot(&narrow, false).unwrap_err();
+        assert!(err.to_string().contains("has width 3"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_eq() -> PolarsResult<()> {
+        let a = int_arrays(
+            "a",
+            vec![
+                Some(vec![Some(1), Some(2), None]),
+                Some(vec![Some(4), Some(5), Some(6)]),
+                None,
+            ],
+            3,
+        );
+        let b = int_arrays(
+            "b",
+            vec![
+                Some(vec![Some(1), Some(0), Some(3)]),
+                Some(vec![Some(4), None, Some(6)]),
+                Some(vec![None; 3]),
+            ],
+            3,
+        );
+        let booleans = |rows: Vec<Option<[Option<bool>; 3]>>| {
+            let rows = rows
+                .into_iter()
+                .map(|row| row.map(|row| Series::new(PlSmallStr::EMPTY, row)))
+                .collect::<Vec<_>>();
+            Series::new("a".into(), rows)
+                .cast(&DataType::Array(Box::new(DataType::Boolean), 3))
+                .unwrap()
+        };
+
+        let out = a.array_eq(&b, true)?.into_series();
+        let expected = booleans(vec![
+            Some([Some(true), Some(false), None]),
+            Some([Some(true), None, Some(true)]),
+            None,
+        ]);
+        assert!(out.equals_missing(&expected));
+
+        let out = a.array_eq(&b, false)?.into_series();
+        let expected = booleans(vec![
+            Some([Some(true), Some(false), Some(false)]),
+            Some([Some(true), Some(false), Some(true)]),
+            None,
+        ]);
+        assert!(out.equals_missing(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_arg_min_max() -> PolarsResult<()> {
+        let rows = [
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(3i32), Some(1), Some(1), Some(3)],
+            )),
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [None, Some(2i32), None, Some(5)],
+            )),
+            Some(Series::new(PlSmallStr::EMPTY, [None::<i32>; 4])),
+            None,
+        ];
+        let s =
+            Series::new("a".into(), rows).cast(&DataType::Array(Box::new(DataType::Int32), 4))?;
+        let ca = s.array()?;
+
+        let expected = [Some(1 as IdxSize), Some(1), None, None];
+        assert_eq!(Vec::from(&ca.array_arg_min()), expected);
+        let expected = [Some(0 as IdxSize), Some(3), None, None];
+        assert_eq!(Vec::from(&ca.array_arg_max()), expected);
+
+        // one-hot rows
+        let s = s.cast(&DataType::Array(Box::new(DataType::Boolean), 4))?;
+        let ca = s.array()?;
+        let expected = [Some(0 as IdxSize), Some(1), None, None];
+        assert_eq!(Vec::from(&ca.array_arg_max()), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "array_any_all")]
+    fn test_array_any_all() -> PolarsResult<()> {
+        let rows = [
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(true), None, Some(false)],
+            )),
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(false), None, Some(false)],
+            )),
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(true), None, Some(true)],
+            )),
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(false), Some(false), Some(false)],
+            )),
+            Some(Series::new(PlSmallStr::EMPTY, [None::<bool>; 3])),
+            None,
+        ];
+        let s =
+            Series::new("a".into(), rows).cast(&DataType::Array(Box::new(DataType::Boolean), 3))?;
+        let ca = s.array()?;
+
+        let out = ca.array_any_kleene()?;
+        let expected = [Some(true), None, Some(true), Some(false), None, None];
+        assert_eq!(Vec::from(out.bool()?), expected);
+
+        let out = ca.array_all_kleene()?;
+        let expected = [Some(false), Some(false), None, Some(false), None, None];
+        assert_eq!(Vec::from(out.bool()?), expected);
+
+        // without Kleene logic null elements are ignored
+        let out = ca.array_any()?;
+        let expected = [
+            Some(true),
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+            None,
+        ];
+        assert_eq!(Vec::from(out.bool()?), expected);
+
+        // zero-width rows
+        let empty = Series::new_empty(PlSmallStr::EMPTY, &DataType::Boolean);
+        let empty = Series::new("a".into(), [Some(empty.clone()), Some(empty), None])
+            .cast(&DataType::Array(Box::new(DataType::Boolean), 0))?;
+        let empty = empty.array()?;
+        let out = empty.array_all()?;
+        assert_eq!(Vec::from(out.bool()?), [Some(true), Some(true), None]);
+        let out = empty.array_all_kleene()?;
+        assert_eq!(Vec::from(out.bool()?), [Some(true), Some(true), None]);
+        let out = empty.array_any()?;
+        assert_eq!(Vec::from(out.bool()?), [Some(false), Some(false), None]);
+        let out = empty.array_any_kleene()?;
+        assert_eq!(Vec::from(out.bool()?), [Some(false), Some(false), None]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_cast() -> PolarsResult<()> {
+        let ca = int_arrays("a", vec![Some(vec![1, 2]), None], 2);
+        let out = ca.array_cast(&DataType::Float64, true)?;
+        let expected = Series::new(
+            "a".into(),
+            [Some(Series::new(PlSmallStr::EMPTY, [1.0f64, 2.0])), None],
+        )
+        .cast(&DataType::Array(Box::new(DataType::Float64), 2))?;
+        assert!(out.into_series().equals_missing(&expected));
+
+        let floats = Series::new(
+            "a".into(),
+            [Some(Series::new(PlSmallStr::EMPTY, [1.5f64, 3e10])), None],
+        )
+        .cast(&DataType::Array(Box::new(DataType::Float64), 2))?;
+        let floats = floats.array()?;
+        assert!(floats.array_cast(&DataType::Int32, true).is_err());
+
+        let out = floats.array_cast(&DataType::Int32, false)?;
+        let expected = Series::new(
+            "a".into(),
+            [
+                Some(Series::new(PlSmallStr::EMPTY, [Some(1i32), None])),
+                None,
+            ],
+        )
+        .cast(&DataType::Array(Box::new(DataType::Int32), 2))?;
+        assert!(out.into_series().equals_missing(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_join() -> PolarsResult<()> {
+        let rows = [
+            Some(Series::new(PlSmallStr::EMPTY, [Some("a"), None, Some("c")])),
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some("d"), Some("e"), Some("f")],
+            )),
+            None,
+        ];
+        let s =
+            Series::new("a".into(), rows).cast(&DataType::Array(Box::new(DataType::String), 3))?;
+        let ca = s.array()?;
+        let separator = StringChunked::from_slice("sep".into(), &["-"]);
+
+        let out = ca.array_join(&separator, true)?;
+        assert_eq!(Vec::from(out.str()?), [Some("a-c"), Some("d-e-f"), None]);
+
+        let out = ca.array_join(&separator, false)?;
+        assert_eq!(Vec::from(out.str()?), [None, Some("d-e-f"), None]);
+
+        let empty = Series::new_empty(PlSmallStr::EMPTY, &DataType::String);
+        let s = Series::new("a".into(), [Some(empty), None])
+            .cast(&DataType::Array(Box::new(DataType::String), 0))?;
+        let out = s.array()?.array_join(&separator, false)?;
+        assert_eq!(Vec::from(out.str()?), [Some(""), None]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_quantile() -> PolarsResult<()> {
+        let rows = [
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(4i32), Some(1), None, Some(2)],
+            )),
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(1i32), Some(2), Some(3), Some(4)],
+            )),
+            Some(Series::new(PlSmallStr::EMPTY, [None::<i32>; 4])),
+            None,
+        ];
+        let s =
+            Series::new("a".into(), rows).cast(&DataType::Array(Box::new(DataType::Int32), 4))?;
+        let ca = s.array()?;
+
+        // odd and even number of non-null elements
+        let out = ca.array_quantile(0.5, QuantileMethod::Linear)?;
+        assert_eq!(Vec::from(out.f64()?), [Some(2.0), Some(2.5), None, None]);
+        assert!(out.equals_missing(&ca.array_median()?));
+
+        let out = ca.array_quantile(0.5, QuantileMethod::Lower)?;
+        assert_eq!(Vec::from(out.f64()?), [Some(2.0), Some(2.0), None, None]);
+        let out = ca.array_quantile(0.5, QuantileMethod::Higher)?;
+        assert_eq!(Vec::from(out.f64()?), [Some(2.0), Some(3.0), None, None]);
+
+        assert!(ca.array_quantile(1.5, QuantileMethod::Linear).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "list_gather")]
+    fn test_array_take() -> PolarsResult<()> {
+        let ca = int_arrays("a", vec![Some(vec![1, 2, 3]), Some(vec![4, 5, 6]), None], 3);
+        let indices = int_arrays(
+            "idx",
+            vec![Some(vec![2, 0]), Some(vec![-1, 3]), Some(vec![0, 0])],
+            2,
+        );
+
+        let out = ca.array_take(&indices, true)?.into_series();
+        let expected = int_arrays(
+            "a",
+            vec![
+                Some(vec![Some(3), Some(1)]),
+                Some(vec![Some(6), None]),
+                None,
+            ],
+            2,
+        );
+        assert!(out.equals_missing(&expected.into_series()));
+
+        assert!(ca.array_take(&indices, false).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_set() -> PolarsResult<()> {
+        let ca = int_arrays("a", vec![Some(vec![1, 2, 3]), None, Some(vec![4, 5, 6])], 3);
+        let arrays = |rows: Vec<Option<Vec<Option<i32>>>>| int_arrays("a", rows, 3).into_series();
+
+        let out = ca.array_set(1, &Series::new("v".into(), [0i32]))?;
+        let expected = arrays(vec![
+            Some(vec![Some(1), Some(0), Some(3)]),
+            None,
+            Some(vec![Some(4), Some(0), Some(6)]),
+        ]);
+        assert!(out.into_series().equals_missing(&expected));
+
+        let values = Series::new("v".into(), [None, Some(8i64), Some(9)]);
+        let out = ca.array_set(2, &values)?;
+        let expected = arrays(vec![
+            Some(vec![Some(1), Some(2), None]),
+            None,
+            Some(vec![Some(4), Some(5), Some(9)]),
+        ]);
+        assert!(out.into_series().equals_missing(&expected));
+
+        assert!(ca.array_set(3, &values).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_to_list() -> PolarsResult<()> {
+        let ca = int_arrays("a", vec![Some(vec![1, 2]), None, Some(vec![3, 4])], 2);
+        let out = ca.array_to_list();
+
+        let expected = Series::new(
+            "a".into(),
+            [
+                Some(Series::new(PlSmallStr::EMPTY, [1i32, 2])),
+                None,
+                Some(Series::new(PlSmallStr::EMPTY, [3i32, 4])),
+            ],
+        );
+        assert!(out.clone().into_series().equals_missing(&expected));
+
+        // the elements are shared, not copied
+        let values = |arr: &dyn Array| {
+            arr.as_any()
+                .downcast_ref::<arrow::array::PrimitiveArray<i32>>()
+                .unwrap()
+                .values()
+                .as_ptr()
+        };
+        let list_values = out.downcast_iter().next().unwrap().values().clone();
+        let array_values = ca.downcast_iter().next().unwrap().values().clone();
+        assert_eq!(values(list_values.as_ref()), values(array_values.as_ref()));
+
+        let round_trip = out.cast(ca.dtype())?;
+        assert!(round_trip.equals_missing(&ca.into_series()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_rolling_mean() -> PolarsResult<()> {
+        let row = [
+            Some(1.0f64),
+            Some(2.0),
+            Some(3.0),
+            None,
+            Some(5.0),
+            Some(9.0),
+        ];
+        let s = Series::new(
+            "a".into(),
+            [Some(Series::new(PlSmallStr::EMPTY, row)), None],
+        )
+        .cast(&DataType::Array(Box::new(DataType::Float64), 6))?;
+
+        let out = s.array()?.array_rolling_mean(3)?.into_series();
+        let row = [None, None, Some(2.0), Some(2.5), Some(4.0), Some(7.0)];
+        let expected = Series::new(
+            "a".into(),
+            [Some(Series::new(PlSmallStr::EMPTY, row)), None],
+        )
+        .cast(&DataType::Array(Box::new(DataType::Float64), 6))?;
+        assert!(out.equals_missing(&expected));
+
+        assert!(s.array()?.array_rolling_mean(0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_norm() -> PolarsResult<()> {
+        let rows = [
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(3.0f64), Some(-4.0), Some(0.0)],
+            )),
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(1.0f64), None, Some(-2.0)],
+            )),
+            Some(Series::new(PlSmallStr::EMPTY, [None::<f64>, None, None])),
+            None,
+        ];
+        let a =
+            Series::new("a".into(), rows).cast(&DataType::Array(Box::new(DataType::Float64), 3))?;
+        let a = a.array()?;
+
+        let out = a.array_norm(NormOrd::L1)?;
+        assert_eq!(Vec::from(out.f64()?), [Some(7.0), Some(3.0), None, None]);
+
+        let out = a.array_norm(NormOrd::L2)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            [Some(5.0), Some(5.0f64.sqrt()), None, None]
+        );
+
+        let out = a.array_norm(NormOrd::LInf)?;
+        assert_eq!(Vec::from(out.f64()?), [Some(4.0), Some(2.0), None, None]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_clip() -> PolarsResult<()> {
+        let a = int_arrays(
+            "a",
+            vec![Some(vec![-5, 0, 5, 10]), Some(vec![1, 2, 3, 4]), None],
+            4,
+        );
+
+        let out = a.array_clip(Some(AnyValue::Int32(0)), Some(AnyValue::Int32(5)))?;
+        let expected = int_arrays(
+            "a",
+            vec![Some(vec![0, 0, 5, 5]), Some(vec![1, 2, 3, 4]), None],
+            4,
+        );
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        let out = a.array_clip(Some(AnyValue::Int32(2)), None)?;
+        let expected = int_arrays(
+            "a",
+            vec![Some(vec![2, 2, 5, 10]), Some(vec![2, 2, 3, 4]), None],
+            4,
+        );
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        let out = a.array_clip(None, Some(AnyValue::Int32(3)))?;
+        let expected = int_arrays(
+            "a",
+            vec![Some(vec![-5, 0, 3, 3]), Some(vec![1, 2, 3, 3]), None],
+            4,
+        );
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "interpolate")]
+    fn test_array_interpolate() -> PolarsResult<()> {
+        let dtype = DataType::Array(Box::new(DataType::Float64), 6);
+        let row = |values: [Option<f64>; 6]| Some(Series::new(PlSmallStr::EMPTY, values));
+        let a = Series::new(
+            "a".into(),
+            [
+                row([None, Some(1.0), None, None, Some(4.0), None]),
+                row([Some(0.0), None, Some(1.0), Some(2.0), None, Some(4.0)]),
+                None,
+            ],
+        )
+        .cast(&dtype)?;
+        let a = a.array()?;
+
+        let out = a.array_interpolate(InterpolationMethod::Linear)?;
+        let expected = Series::new(
+            "a".into(),
+            [
+                row([None, Some(1.0), Some(2.0), Some(3.0), Some(4.0), None]),
+                row([
+                    Some(0.0),
+                    Some(0.5),
+                    Some(1.0),
+                    Some(2.0),
+                    Some(3.0),
+                    Some(4.0),
+                ]),
+                None,
+            ],
+        )
+        .cast(&dtype)?;
+        assert!(out.into_series().equals_missing(&expected));
+
+        let out = a.array_interpolate(InterpolationMethod::Nearest)?;
+        let expected = Series::new(
+            "a".into(),
+            [
+                row([None, Some(1.0), Some(1.0), Some(4.0), Some(4.0), None]),
+                row([
+                    Some(0.0),
+                    Some(1.0),
+                    Some(1.0),
+                    Some(2.0),
+                    Some(4.0),
+                    Some(4.0),
+                ]),
+                None,
+            ],
+        )
+        .cast(&dtype)?;
+        assert!(out.into_series().equals_missing(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_pad() -> PolarsResult<()> {
+        let a = int_arrays("a", vec![Some(vec![1, 2, 3]), None], 3);
+
+        let out = a.array_pad(5, PadSide::Start, AnyValue::Int32(0))?;
+        let expected = int_arrays("a", vec![Some(vec![0, 0, 1, 2, 3]), None], 5);
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        let out = a.array_pad(5, PadSide::End, AnyValue::Int32(0))?;
+        let expected = int_arrays("a", vec![Some(vec![1, 2, 3, 0, 0]), None], 5);
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        let dtype = DataType::Array(Box::new(DataType::Int32), 5);
+        let out = a.array_pad(5, PadSide::Start, AnyValue::Null)?;
+        let expected = Series::new(
+            "a".into(),
+            [
+                Some(Series::new(
+                    PlSmallStr::EMPTY,
+                    [None, None, Some(1i32), Some(2), Some(3)],
+                )),
+                None,
+            ],
+        )
+        .cast(&dtype)?;
+        assert!(out.into_series().equals_missing(&expected));
+
+        let out = a.array_pad(5, PadSide::End, AnyValue::Null)?;
+        let expected = Series::new(
+            "a".into(),
+            [
+                Some(Series::new(
+                    PlSmallStr::EMPTY,
+                    [Some(1i32), Some(2), Some(3), None, None],
+                )),
+                None,
+            ],
+        )
+        .cast(&dtype)?;
+        assert!(out.into_series().equals_missing(&expected));
+
+        assert!(a.array_pad(2, PadSide::End, AnyValue::Int32(0)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_slice_each() -> PolarsResult<()> {
+        let a = int_arrays(
+            "a",
+            vec![
+                Some(vec![1, 2, 3, 4]),
+                Some(vec![5, 6, 7, 8]),
+                Some(vec![9, 10, 11, 12]),
+                None,
+            ],
+            4,
+        );
+        let int_lists = |rows: Vec<Option<Vec<i32>>>| {
+            let rows = rows
+                .into_iter()
+                .map(|row| row.map(|row| Series::new(PlSmallStr::EMPTY, row)))
+                .collect::<Vec<_>>();
+            Series::new("a".into(), rows)
+        };
+        let offsets = Series::new("offsets".into(), [0i64, -2, 3, 0]);
+        let lengths = Series::new("lengths".into(), [2i64, 2, 3, 1]);
+
+        let out = a.array_slice_each(&offsets, &lengths, false)?;
+        let expected = int_lists(vec![
+            Some(vec![1, 2]),
+            Some(vec![7, 8]),
+            Some(vec![12]),
+            None,
+        ]);
+        assert!(out.into_series().equals_missing(&expected));
+
+        let out = a.array_slice_each(&offsets, &lengths, true)?;
+        let expected = int_lists(vec![Some(vec![1, 2]), Some(vec![7, 8]), None, None]);
+        assert!(out.into_series().equals_missing(&expected));
+
+        let out = a.array_slice_each(&Series::new("offsets".into(), [1i64]), &lengths, false)?;
+        let expected = int_lists(vec![
+            Some(vec![2, 3]),
+            Some(vec![6, 7]),
+            Some(vec![10, 11, 12]),
+            None,
+        ]);
+        assert!(out.into_series().equals_missing(&expected));
+
+        let negative = Series::new("lengths".into(), [-1i64]);
+        assert!(a.array_slice_each(&offsets, &negative, false).is_err());
+
+        // Windows that run far past the end don't overflow.
+        let offsets = Series::new("offsets".into(), [1i64, -2, 0, 0]);
+        let lengths = Series::new("lengths".into(), [i64::MAX]);
+        let out = a.array_slice_each(&offsets, &lengths, false)?;
+        let expected = int_lists(vec![
+            Some(vec![2, 3, 4]),
+            Some(vec![7, 8]),
+            Some(vec![9, 10, 11, 12]),
+            None,
+        ]);
+        assert!(out.into_series().equals_missing(&expected));
+
+        let out = a.array_slice_each(&offsets, &lengths, true)?;
+        assert_eq!(out.null_count(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_zip_with() -> PolarsResult<()> {
+        let a = int_arrays(
+            "a",
+            vec![Some(vec![1, 2, 3, 4]), Some(vec![5, 6, 7, 8]), None],
+            4,
+        );
+        let rows = [
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(1i32), Some(0), None, Some(2)],
+            )),
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(2i32), Some(3), Some(7), Some(0)],
+            )),
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(1i32), Some(1), Some(1), Some(1)],
+            )),
+        ];
+        let b =
+            Series::new("b".into(), rows).cast(&DataType::Array(Box::new(DataType::Int32), 4))?;
+        let b = b.array()?;
+
+        let out = a.array_zip_with(b, ArrZipOp::Add)?;
+        let out = out.get_inner();
+        assert_eq!(
+            Vec::from(out.i32()?)[..8],
+            [
+                Some(2),
+                Some(2),
+                None,
+                Some(6),
+                Some(7),
+                Some(9),
+                Some(14),
+                Some(8),
+            ]
+        );
+
+        // Integer division by zero gives null.
+        let out = a.array_zip_with(b, ArrZipOp::Div)?;
+        assert!(out.get(2).is_none());
+        let out = out.get_inner();
+        assert_eq!(
+            Vec::from(out.i32()?)[..8],
+            [
+                Some(1),
+                None,
+                None,
+                Some(2),
+                Some(2),
+                Some(2),
+                Some(1),
+                None,
+            ]
+        );
+
+        // Float division by zero gives infinity.
+        let a = a.array_cast(&DataType::Float64, true)?;
+        let out = a.array_zip_with(b, ArrZipOp::Div)?;
+        let out = out.get_inner();
+        assert_eq!(
+            Vec::from(out.f64()?)[..8],
+            [
+                Some(1.0),
+                Some(f64::INFINITY),
+                None,
+                Some(2.0),
+                Some(2.5),
+                Some(2.0),
+                Some(1.0),
+                Some(f64::INFINITY),
+            ]
+        );
+
+        let out = a.array_zip_with(b, ArrZipOp::Max)?;
+        let out = out.get_inner();
+        assert_eq!(
+            Vec::from(out.f64()?)[..4],
+            [Some(1.0), Some(2.0), None, Some(4.0)]
+        );
+
+        let narrow = int_arrays("c", vec![Some(vec![1, 2]); 3], 2);
+        assert!(a.array_zip_with(&narrow, ArrZipOp::Add).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "mode")]
+    fn test_array_mode() -> PolarsResult<()> {
+        let a = int_arrays(
+            "a",
+            vec![Some(vec![3, 1, 3, 2]), Some(vec![4, 2, 4, 2]), None],
+            4,
+        );
+        let out = a.array_mode()?;
+        assert_eq!(Vec::from(out.i32()?), [Some(3), Some(2), None]);
+
+        let rows = [
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [None, Some(5i32), None, Some(5)],
+            )),
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [None::<i32>, None, None, None],
+            )),
+        ];
+        let b =
+            Series::new("b".into(), rows).cast(&DataType::Array(Box::new(DataType::Int32), 4))?;
+        let out = b.array()?.array_mode()?;
+        assert_eq!(Vec::from(out.i32()?), [Some(5), None]);
+
+        Ok(())
+    }
+}
@@ -44,3 +44,49 @@ pub trait ToStruct: AsArray {
 }
 
 impl ToStruct for ArrayChunked {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_struct() -> PolarsResult<()> {
+        let rows = [
+            Some(Series::new(PlSmallStr::EMPTY, [Some(1i32), None, Some(3)])),
+            None,
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(4i32), Some(5), Some(6)],
+            )),
+        ];
+        let s =
+            Series::new("a".into(), rows).cast(&DataType::Array(Box::new(DataType::Int32), 3))?;
+        let ca = s.array()?;
+
+        let out = ca.to_struct(None)?;
+        let fields = out.fields_as_series();
+        let expected = [
+            ("field_0", [Some(1i32), None, Some(4)]),
+            ("field_1", [None, None, Some(5)]),
+            ("field_2", [Some(3), None, Some(6)]),
+        ];
+        assert_eq!(fields.len(), expected.len());
+        for (field, (name, values)) in fields.iter().zip(expected) {
+            assert_eq!(field.name().as_str(), name);
+            assert_eq!(field.dtype(), &DataType::Int32);
+            assert!(field.equals_missing(&Series::new(name.into(), values)));
+        }
+
+        let name_generator: ArrToStructNameGenerator =
+            Arc::new(|i| Ok(format_pl_smallstr!("col{}", i + 1)));
+        let out = ca.to_struct(Some(name_generator))?;
+        let names = out
+            .fields_as_series()
+            .iter()
+            .map(|s| s.name().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["col1", "col2", "col3"]);
+
+        Ok(())
+    }
+}
@@ -1,16 +1,20 @@
 use std::borrow::Cow;
 use std::fmt::Write;
 
-use arrow::array::ValueSize;
+use arrow::array::{Array, ListArray, ValueSize};
+use arrow::offset::{Offsets, OffsetsBuffer};
 #[cfg(feature = "list_gather")]
 use num_traits::ToPrimitive;
 #[cfg(feature = "list_gather")]
 use num_traits::{NumCast, Signed, Zero};
 use polars_compute::gather::sublist::list::{index_is_oob, sublist_get};
+use polars_compute::horizontal_flatten::horizontal_flatten_unchecked;
 use polars_core::chunked_array::builder::get_list_builder;
 #[cfg(feature = "diff")]
 use polars_core::series::ops::NullBehavior;
 use polars_core::utils::try_get_supertype;
+#[cfg(feature = "dtype-struct")]
+use polars_core::utils::{SuperTypeFlags, try_get_supertype_with_options};
 
 use super::*;
 #[cfg(feature = "list_any_all")]
@@ -77,6 +81,190 @@ fn cast_rhs(
     Ok(())
 }
 
+/// The length of every row in `ca`, if `ca` has no nulls and all its rows have the same length.
+fn uniform_list_width(ca: &ListChunked) -> Option<usize> {
+    if ca.has_nulls() {
+        return None;
+    }
+    let mut width = None;
+    for arr in ca.downcast_iter() {
+        for length in arr.offsets().lengths() {
+            match width {
+                None => width = Some(length),
+                Some(width) if width != length => return None,
+                _ => {},
+            }
+        }
+    }
+    width
+}
+
+/// Concatenate lists of which every input has the same length in all rows. The output offsets are
+/// then a fixed stride, and the values can be flattened horizontally like `concat_arr` does,
+/// instead of being appended row by row.
+///
+/// All `lists` must have the same dtype and length, and `widths` must hold their row lengths.
+fn concat_uniform_lists(lists: &[&ListChunked], widths: &[usize]) -> ListChunked {
+    let first = lists[0];
+    let height = first.len();
+    let out_width = widths.iter().sum::<usize>();
+
+    let lists = lists.iter().map(|ca| ca.rechunk()).collect::<Vec<_>>();
+    let (arrays, widths): (Vec<_>, Vec<_>) = lists
+        .iter()
+        .zip(widths)
+        .filter(|(_, width)| **width > 0)
+        .map(|(ca, width)| {
+            let arr = ca.downcast_as_array();
+            let start = *arr.offsets().first() as usize;
+            (arr.values().sliced(start, width * height), *width)
+        })
+        .unzip();
+
+    let arr = lists[0].downcast_as_array();
+    let values = if arrays.is_empty() {
+        arr.values().sliced(0, 0)
+    } else {
+        // SAFETY: all arrays have the same dtype, non-zero width and `width * height` values.
+        unsafe { horizontal_flatten_unchecked(&arrays, &widths, height) }
+    };
+    let offsets = (0..=height)
+        .map(|i| (i * out_width) as i64)
+        .collect::<Vec<_>>();
+    // SAFETY: the offsets are monotonically increasing and end at the length of `values`.
+    let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+    let arr = ListArray::<i64>::new(arr.dtype().clone(), offsets, values, None);
+
+    // SAFETY: the values have the same physical dtype as the input.
+    unsafe {
+        ListChunked::from_chunks_and_dtype(
+            first.name().clone(),
+            vec![arr.boxed()],
+            first.dtype().clone(),
+        )
+    }
+}
+
+/// Reshape a non-list column to a list column with one element per row, like `lst_concat` does,
+/// except that null values become empty lists.
+fn non_null_as_list(c: &Column) -> PolarsResult<Column> {
+    // Keep scalars at unit length, `lst_concat` broadcasts them.
+    let s = c.as_materialized_series_maintain_scalar();
+    let is_valid = s.is_not_null();
+    let lengths = is_valid.into_no_null_iter().map(|v| v as usize);
+    let offsets: OffsetsBuffer<i64> = Offsets::try_from_lengths(lengths)?.into();
+
+    let values = s.drop_nulls();
+    let values = values.to_physical_repr().rechunk();
+    let values = values.chunks()[0].clone();
+    let arr = ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(values.dtype().clone()),
+        offsets,
+        values,
+        None,
+    );
+
+    // SAFETY: the values are the physical values of `s`.
+    let out = unsafe {
+        ListChunked::from_chunks_and_dtype(
+            s.name().clone(),
+            vec![arr.boxed()],
+            DataType::List(Box::new(s.dtype().clone())),
+        )
+    }
+    .into_column();
+
+    Ok(if out.len() != c.len() {
+        out.new_from_index(0, c.len())
+    } else {
+        out
+    })
+}
+
+/// How [`ListNameSpaceImpl::lst_concat_with_null_behavior`] handles null list entries.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ListConcatNullBehavior {
+    /// The output row is null if any of the concatenated entries is null.
+    #[default]
+    PropagateNulls,
+    /// Null entries are skipped. The output row is only null if all entries are null.
+    IgnoreNulls,
+    /// Null entries are treated as empty lists. The output row is never null.
+    TreatAsEmpty,
+}
+
+/// The length every input list is padded to by [`ListNameSpaceImpl::lst_concat_padded`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PadStrategy {
+    /// Pad to the length of the longest input list in the row.
+    MaxAcrossInputs,
+    /// Pad to a fixed length. Input lists longer than this are an error.
+    Fixed(usize),
+}
+
+/// Remove duplicates from `s`, keeping the elements selected by `keep` in a stable order.
+fn unique_with_keep(s: &Series, keep: UniqueKeepStrategy) -> PolarsResult<Series> {
+    match keep {
+        UniqueKeepStrategy::First | UniqueKeepStrategy::Any => s.unique_stable(),
+        UniqueKeepStrategy::Last => Ok(s.reverse().unique_stable()?.reverse()),
+        UniqueKeepStrategy::None => {
+            // An element occurs once if it is both the first and the last of its value.
+            let len = s.len();
+            let mut occurrences = vec![0u8; len];
+            for idx in s.arg_unique()?.into_no_null_iter() {
+                occurrences[idx as usize] += 1;
+            }
+            for idx in s.reverse().arg_unique()?.into_no_null_iter() {
+                occurrences[len - 1 - idx as usize] += 1;
+            }
+            let mask = BooleanChunked::from_iter_values(
+                PlSmallStr::EMPTY,
+                occurrences.into_iter().map(|n| n == 2),
+            );
+            s.filter(&mask)
+        },
+    }
+}
+
+/// Ensure that the struct fields of two `lst_concat` inputs have the same names, in the same
+/// order, and that their dtypes can be combined.
+#[cfg(feature = "dtype-struct")]
+fn check_struct_fields_match(
+    lhs_name: &PlSmallStr,
+    lhs_fields: &[Field],
+    rhs_name: &PlSmallStr,
+    rhs_fields: &[Field],
+) -> PolarsResult<()> {
+    for (i, (lhs, rhs)) in lhs_fields.iter().zip(rhs_fields).enumerate() {
+        polars_ensure!(
+            lhs.name == rhs.name,
+            SchemaMismatch: "lst_concat: struct field {} is named '{}' in column '{}', but '{}' in column '{}'",
+            i, lhs.name, lhs_name, rhs.name, rhs_name
+        );
+        // Don't silently turn mismatching fields into strings.
+        let options = SuperTypeFlags::empty().into();
+        if try_get_supertype_with_options(&lhs.dtype, &rhs.dtype, options).is_err() {
+            polars_bail!(
+                SchemaMismatch: "lst_concat: struct field '{}' has dtype {} in column '{}', which cannot be combined with dtype {} in column '{}'",
+                lhs.name, lhs.dtype, lhs_name, rhs.dtype, rhs_name
+            );
+        }
+    }
+    if lhs_fields.len() != rhs_fields.len() {
+        let (longest, name) = if lhs_fields.len() > rhs_fields.len() {
+            (lhs_fields, lhs_name)
+        } else {
+            (rhs_fields, rhs_name)
+        };
+        let field = &longest[lhs_fields.len().min(rhs_fields.len())];
+        polars_bail!(
+            SchemaMismatch: "lst_concat: struct field '{}' only exists in column '{}'",
+            field.name, name
+        );
+    }
+    Ok(())
+}
+
 pub trait ListNameSpaceImpl: AsList {
     /// In case the inner dtype [`DataType::String`], the individual items will be joined into a
     /// single string separated by `separator`.
@@ -92,7 +280,15 @@ pub trait ListNameSpaceImpl: AsList {
                     Some(separator) => self.join_literal(separator, ignore_nulls),
                     _ => Ok(StringChunked::full_null(ca.name().clone(), ca.len())),
                 },
-                _ => self.join_many(separator, ignore_nulls),
+                len => {
+                    polars_ensure!(
+                        len == ca.len(),
+                        length_mismatch = "list.join",
+                        ca.len(),
+                        len
+                    );
+                    self.join_many(separator, ignore_nulls)
+                },
             },
             dt => polars_bail!(op = "`lst.join`", got = dt, expected = "String"),
         }
@@ -692,21 +888,164 @@ pub trait ListNameSpaceImpl: AsList {
     }
 
     fn lst_concat(&self, other: &[Column]) -> PolarsResult<ListChunked> {
+        self.lst_concat_with_null_behavior(other, ListConcatNullBehavior::default())
+    }
+
+    /// Same as [`ListNameSpaceImpl::lst_concat`], but consumes the columns one at a time, so they
+    /// don't have to be materialized together. Every column is concatenated to the result as soon
+    /// as it is produced, which copies the result once per column, so prefer
+    /// [`ListNameSpaceImpl::lst_concat`] for many columns that are in memory already.
+    fn lst_concat_iter(&self, other: impl IntoIterator<Item = Column>) -> PolarsResult<ListChunked>
+    where
+        Self: Sized,
+    {
+        // The inner supertype is resolved from left to right, like `lst_concat` does.
+        other
+            .into_iter()
+            .try_fold(self.as_list().clone(), |acc, c| {
+                acc.lst_concat(std::slice::from_ref(&c))
+            })
+    }
+
+    /// Same as [`ListNameSpaceImpl::lst_concat`], but requires the (inner) dtypes of all inputs to
+    /// be equal instead of casting them to their supertype.
+    fn lst_concat_strict(&self, other: &[Column]) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        let inner_dtype = ca.inner_dtype();
+        for c in other {
+            let dt = match c.dtype() {
+                DataType::List(inner_type) => inner_type.as_ref(),
+                dt => dt,
+            };
+            polars_ensure!(
+                dt == inner_dtype,
+                SchemaMismatch: "lst_concat_strict: column '{}' has dtype {}, expected {} or list[{}] to match column '{}'",
+                c.name(), c.dtype(), inner_dtype, inner_dtype, ca.name()
+            );
+        }
+        self.lst_concat(other)
+    }
+
+    /// Same as [`ListNameSpaceImpl::lst_concat`], which appends the value of every non-list column
+    /// in `other` as a single element. With `append_null_scalars` unset, null values of these
+    /// columns append nothing instead of a null element.
+    fn lst_concat_with_scalars(
+        &self,
+        other: &[Column],
+        append_null_scalars: bool,
+    ) -> PolarsResult<ListChunked> {
+        if append_null_scalars {
+            return self.lst_concat(other);
+        }
+        let other = other
+            .iter()
+            .map(|c| match c.dtype() {
+                DataType::List(_) => Ok(c.clone()),
+                _ => non_null_as_list(c),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        self.lst_concat(&other)
+    }
+
+    /// Same as [`ListNameSpaceImpl::lst_concat`], but also returns a list of strings with the same
+    /// shape as the output, which holds the name of the column every element came from.
+    fn lst_concat_with_names(&self, other: &[Column]) -> PolarsResult<(ListChunked, ListChunked)> {
         let ca = self.as_list();
+        let out = self.lst_concat(other)?;
+
+        let tags_dtype = DataType::List(Box::new(DataType::String));
+        let list_tags = |lst: &ListChunked, name: &PlSmallStr| {
+            lst.apply_amortized(|s| {
+                Series::new(PlSmallStr::EMPTY, vec![name.as_str(); s.as_ref().len()])
+            })
+            .cast(&tags_dtype)
+        };
+        let first = list_tags(ca, ca.name())?;
+        let other_tags = other
+            .iter()
+            .map(|c| match c.dtype() {
+                DataType::List(_) => Ok(list_tags(c.list()?, c.name())?.into_column()),
+                // every value becomes a single element
+                _ => Ok(Column::new_scalar(
+                    c.name().clone(),
+                    Scalar::from(c.name().clone()),
+                    c.len(),
+                )),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let tags = first.list()?.lst_concat(&other_tags)?;
+
+        Ok((out, tags))
+    }
+
+    /// Same as [`ListNameSpaceImpl::lst_concat`], but with `chunk_size` set the output is split
+    /// into chunks of `chunk_size` rows, apart from the last one, which may be shorter.
+    fn lst_concat_with_chunk_size(
+        &self,
+        other: &[Column],
+        chunk_size: Option<usize>,
+    ) -> PolarsResult<ListChunked> {
+        let out = self.lst_concat(other)?;
+        let Some(chunk_size) = chunk_size else {
+            return Ok(out);
+        };
+        polars_ensure!(chunk_size > 0, ComputeError: "lst_concat: chunk_size must be positive");
+
+        let out = out.rechunk();
+        let chunks = (0..out.len())
+            .step_by(chunk_size)
+            .map(|offset| {
+                out.downcast_as_array()
+                    .sliced(offset, chunk_size.min(out.len() - offset))
+            })
+            .collect::<Vec<_>>();
+        // SAFETY: the chunks are slices of `out`, which has the same dtype.
+        Ok(unsafe {
+            ListChunked::from_chunks_and_dtype(out.name().clone(), chunks, out.dtype().clone())
+        })
+    }
+
+    fn lst_concat_with_null_behavior(
+        &self,
+        other: &[Column],
+        null_behavior: ListConcatNullBehavior,
+    ) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        // nothing to concatenate, only `TreatAsEmpty` can change the output
+        if other.is_empty()
+            && (null_behavior != ListConcatNullBehavior::TreatAsEmpty || !ca.has_nulls())
+        {
+            return Ok(ca.clone());
+        }
+
         let other_len = other.len();
         let length = ca.len();
         let mut other = other.to_vec();
         let mut inner_super_type = ca.inner_dtype().clone();
 
         for s in &other {
-            match s.dtype() {
-                DataType::List(inner_type) => {
-                    inner_super_type = try_get_supertype(&inner_super_type, inner_type)?;
-                },
-                dt => {
-                    inner_super_type = try_get_supertype(&inner_super_type, dt)?;
-                },
+            let dt = match s.dtype() {
+                DataType::List(inner_type) => inner_type.as_ref(),
+                dt => dt,
+            };
+            #[cfg(feature = "dtype-struct")]
+            if let (DataType::Struct(lhs_fields), DataType::Struct(rhs_fields)) =
+                (ca.inner_dtype(), dt)
+            {
+                check_struct_fields_match(ca.name(), lhs_fields, s.name(), rhs_fields)?;
             }
+            #[cfg(feature = "dtype-array")]
+            if let (DataType::Array(_, lhs_width), DataType::Array(_, rhs_width)) =
+                (ca.inner_dtype(), dt)
+            {
+                polars_ensure!(
+                    lhs_width == rhs_width,
+                    SchemaMismatch: "cannot concat lists of arrays with differing widths: column \
+                    '{}' has width {}, but column '{}' has width {}",
+                    ca.name(), lhs_width, s.name(), rhs_width,
+                );
+            }
+            inner_super_type = try_get_supertype(&inner_super_type, dt)?;
         }
 
         // cast lhs
@@ -731,7 +1070,9 @@ pub trait ListNameSpaceImpl: AsList {
                 .collect::<Vec<_>>();
 
             // there was a None, so all values will be None
-            if to_append.len() != other_len {
+            if to_append.len() != other_len
+                && null_behavior == ListConcatNullBehavior::PropagateNulls
+            {
                 return Ok(ListChunked::full_null_with_dtype(
                     ca.name().clone(),
                     length,
@@ -751,19 +1092,26 @@ pub trait ListNameSpaceImpl: AsList {
                 ca.name().clone(),
             );
             ca.into_iter().for_each(|opt_s| {
-                let opt_s = opt_s.map(|mut s| {
-                    for append in &to_append {
-                        s.append(append).unwrap();
-                    }
-                    match inner_super_type {
-                        // structs don't have chunks, so we must first rechunk the underlying series
-                        #[cfg(feature = "dtype-struct")]
-                        DataType::Struct(_) => s = s.rechunk(),
-                        // nothing
-                        _ => {},
-                    }
-                    s
-                });
+                let opt_s = match (opt_s, null_behavior) {
+                    (None, ListConcatNullBehavior::PropagateNulls) => None,
+                    (None, ListConcatNullBehavior::IgnoreNulls) if to_append.is_empty() => None,
+                    (opt_s, _) => {
+                        let mut s = opt_s.unwrap_or_else(|| {
+                            Series::new_empty(PlSmallStr::EMPTY, &inner_super_type)
+                        });
+                        for append in &to_append {
+                            s.append(append).unwrap();
+                        }
+                        match inner_super_type {
+                            // structs don't have chunks, so we must first rechunk the underlying series
+                            #[cfg(feature = "dtype-struct")]
+                            DataType::Struct(_) => s = s.rechunk(),
+                            // nothing
+                            _ => {},
+                        }
+                        Some(s)
+                    },
+                };
                 builder.append_opt_series(opt_s.as_ref()).unwrap();
             });
             builder.finish()
@@ -771,6 +1119,19 @@ pub trait ListNameSpaceImpl: AsList {
             // normal path which may contain same length list or unit length lists
             cast_rhs(&mut other, &inner_super_type, dtype, length, true)?;
 
+            let lists = std::iter::once(ca)
+                .chain(other.iter().map(|s| s.list().unwrap()))
+                .collect::<Vec<_>>();
+            if length > 0 && !inner_super_type.is_object() {
+                let widths = lists
+                    .iter()
+                    .map(|ca| uniform_list_width(ca))
+                    .collect::<Option<Vec<_>>>();
+                if let Some(widths) = widths {
+                    return Ok(concat_uniform_lists(&lists, &widths));
+                }
+            }
+
             let vals_size_other = other
                 .iter()
                 .map(|s| s.list().unwrap().get_values_size())
@@ -789,23 +1150,19 @@ pub trait ListNameSpaceImpl: AsList {
             );
 
             for _ in 0..ca.len() {
-                let mut acc = match first_iter.next().unwrap() {
-                    Some(s) => s,
-                    None => {
-                        builder.append_null();
-                        // make sure that the iterators advance before we continue
-                        for it in &mut iters {
-                            it.next().unwrap();
-                        }
-                        continue;
-                    },
-                };
+                let first = first_iter.next().unwrap();
+                let mut has_nulls = first.is_none();
+                let mut has_values = !has_nulls;
+                let mut acc = first
+                    .unwrap_or_else(|| Series::new_empty(PlSmallStr::EMPTY, &inner_super_type));
 
-                let mut has_nulls = false;
+                // make sure that the iterators always advance, even if the row will be null
                 for it in &mut iters {
                     match it.next().unwrap() {
                         Some(s) => {
-                            if !has_nulls {
+                            has_values = true;
+                            if !has_nulls || null_behavior != ListConcatNullBehavior::PropagateNulls
+                            {
                                 acc.append(s.as_ref())?;
                             }
                         },
@@ -814,7 +1171,13 @@ pub trait ListNameSpaceImpl: AsList {
                         },
                     }
                 }
-                if has_nulls {
+
+                let is_null = match null_behavior {
+                    ListConcatNullBehavior::PropagateNulls => has_nulls,
+                    ListConcatNullBehavior::IgnoreNulls => !has_values,
+                    ListConcatNullBehavior::TreatAsEmpty => false,
+                };
+                if is_null {
                     builder.append_null();
                     continue;
                 }
@@ -832,6 +1195,173 @@ pub trait ListNameSpaceImpl: AsList {
         };
         Ok(out)
     }
+
+    /// Concatenate the lists in every row and remove the duplicate elements of the result.
+    ///
+    /// `keep` selects which occurrence of a duplicated element is kept. The kept elements stay in
+    /// their original order. Nulls are treated as equal to each other, so at most one null is
+    /// kept per row.
+    fn lst_concat_unique(
+        &self,
+        other: &[Column],
+        keep: UniqueKeepStrategy,
+    ) -> PolarsResult<ListChunked> {
+        let out = self.lst_concat(other)?;
+        let out = out.try_apply_amortized(|s| unique_with_keep(s.as_ref(), keep))?;
+        Ok(self.same_type(out))
+    }
+
+    /// Concatenate the lists in every row, padding every input list with nulls up to the length
+    /// given by `pad_to` first.
+    ///
+    /// A row is null if any of the inputs is null in that row.
+    fn lst_concat_padded(
+        &self,
+        other: &[Column],
+        pad_to: PadStrategy,
+    ) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        let length = ca.len();
+
+        let mut inner_super_type = ca.inner_dtype().clone();
+        for c in other {
+            polars_ensure!(
+                c.len() == length,
+                ShapeMismatch: "lst_concat_padded: column '{}' has length {}, expected length {}",
+                c.name(), c.len(), length
+            );
+            inner_super_type = try_get_supertype(&inner_super_type, c.list()?.inner_dtype())?;
+        }
+        let dtype = DataType::List(Box::new(inner_super_type.clone()));
+
+        let mut lists = Vec::with_capacity(other.len() + 1);
+        lists.push(ca.cast(&dtype)?);
+        for c in other {
+            lists.push(c.as_materialized_series().cast(&dtype)?);
+        }
+        let mut iters = lists
+            .iter()
+            .map(|s| s.list().unwrap().amortized_iter())
+            .collect::<Vec<_>>();
+        let mut builder = get_list_builder(&inner_super_type, 0, length, ca.name().clone());
+
+        let mut row = Vec::with_capacity(lists.len());
+        for idx in 0..length {
+            row.clear();
+            let mut has_nulls = false;
+            // make sure that the iterators always advance, even if the row will be null
+            for it in &mut iters {
+                match it.next().unwrap() {
+                    Some(s) => row.push(s.as_ref().clone()),
+                    None => has_nulls = true,
+                }
+            }
+            if has_nulls {
+                builder.append_null();
+                continue;
+            }
+
+            let max_len = row.iter().map(|s| s.len()).max().unwrap_or(0);
+            let target_len = match pad_to {
+                PadStrategy::MaxAcrossInputs => max_len,
+                PadStrategy::Fixed(target_len) => {
+                    polars_ensure!(
+                        max_len <= target_len,
+                        ShapeMismatch: "lst_concat_padded: list of length {} in row {} is longer than the padding length {}",
+                        max_len, idx, target_len
+                    );
+                    target_len
+                },
+            };
+
+            let mut acc = Series::new_empty(PlSmallStr::EMPTY, &inner_super_type);
+            for s in &row {
+                acc.append(s)?;
+                acc.append(&Series::full_null(
+                    PlSmallStr::EMPTY,
+                    target_len - s.len(),
+                    &inner_super_type,
+                ))?;
+            }
+            match inner_super_type {
+                // structs don't have chunks, so we must first rechunk the underlying series
+                #[cfg(feature = "dtype-struct")]
+                DataType::Struct(_) => acc = acc.rechunk(),
+                // nothing
+                _ => {},
+            }
+            builder.append_series(&acc)?;
+        }
+        Ok(builder.finish())
+    }
+
+    /// Zip the elements of this list column with those of `others`.
+    ///
+    /// Every row becomes a list of structs, where the i-th struct holds the i-th element of
+    /// every input list, with one field per input column. The lists in a row must all have
+    /// the same length. A row is null if any of the inputs is null in that row.
+    #[cfg(feature = "dtype-struct")]
+    fn lst_zip(&self, others: &[Column]) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        let length = ca.len();
+
+        let mut lists = Vec::with_capacity(others.len() + 1);
+        lists.push(ca.clone());
+        for c in others {
+            polars_ensure!(
+                c.len() == length,
+                ShapeMismatch: "lst_zip: column '{}' has length {}, expected length {}",
+                c.name(), c.len(), length
+            );
+            lists.push(c.list()?.clone());
+        }
+
+        let fields = lists
+            .iter()
+            .map(|lst| Field::new(lst.name().clone(), lst.inner_dtype().clone()))
+            .collect::<Vec<_>>();
+        let inner_dtype = DataType::Struct(fields);
+
+        let mut iters = lists
+            .iter()
+            .map(|lst| lst.amortized_iter_with_name(lst.name().clone()))
+            .collect::<Vec<_>>();
+        let mut builder = get_list_builder(
+            &inner_dtype,
+            ca.get_values_size(),
+            length,
+            ca.name().clone(),
+        );
+
+        let mut row = Vec::with_capacity(lists.len());
+        for idx in 0..length {
+            row.clear();
+            let mut has_nulls = false;
+            // make sure that the iterators always advance, even if the row will be null
+            for it in &mut iters {
+                match it.next().unwrap() {
+                    Some(s) => row.push(s.as_ref().clone()),
+                    None => has_nulls = true,
+                }
+            }
+            if has_nulls {
+                builder.append_null();
+                continue;
+            }
+
+            let row_len = row[0].len();
+            if let Some(s) = row.iter().find(|s| s.len() != row_len) {
+                polars_bail!(
+                    ShapeMismatch: "lst_zip: lists in row {} have differing lengths: {} in column '{}' and {} in column '{}'",
+                    idx, row_len, row[0].name(), s.len(), s.name()
+                );
+            }
+
+            let st = StructChunked::from_series(PlSmallStr::EMPTY, row_len, row.iter())?;
+            builder.append_series(&st.into_series())?;
+        }
+        Ok(builder.finish())
+    }
 }
 
 impl ListNameSpaceImpl for ListChunked {}
@@ -945,3 +1475,458 @@ fn cast_index(idx: Series, len: usize, null_on_oob: bool) -> PolarsResult<Series
 }
 
 // TODO: implement the above for ArrayChunked as well?
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn int_lists(name: &str, rows: Vec<Option<Vec<i32>>>) -> ListChunked {
+        let rows = rows
+            .into_iter()
+            .map(|row| row.map(|row| Series::new(PlSmallStr::EMPTY, row)))
+            .collect::<Vec<_>>();
+        Series::new(name.into(), rows).list().unwrap().clone()
+    }
+
+    #[test]
+    fn test_lst_concat_null_behavior() -> PolarsResult<()> {
+        let a = int_lists("a", vec![None, Some(vec![1]), None]);
+        let b = int_lists("b", vec![Some(vec![2]), None, None]);
+        let c = int_lists("c", vec![Some(vec![3]), Some(vec![4, 5]), None]);
+        let others = [b.into_column(), c.into_column()];
+
+        let out =
+            a.lst_concat_with_null_behavior(&others, ListConcatNullBehavior::PropagateNulls)?;
+        let expected = ListChunked::full_null_with_dtype("a".into(), 3, &DataType::Int32);
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        let out = a.lst_concat_with_null_behavior(&others, ListConcatNullBehavior::IgnoreNulls)?;
+        let expected = int_lists("a", vec![Some(vec![2, 3]), Some(vec![1, 4, 5]), None]);
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        let out = a.lst_concat_with_null_behavior(&others, ListConcatNullBehavior::TreatAsEmpty)?;
+        let expected = int_lists(
+            "a",
+            vec![Some(vec![2, 3]), Some(vec![1, 4, 5]), Some(vec![])],
+        );
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        // The default matches `lst_concat`.
+        let out = a.lst_concat(&others)?;
+        let expected = ListChunked::full_null_with_dtype("a".into(), 3, &DataType::Int32);
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_lst_zip() -> PolarsResult<()> {
+        let a = int_lists("a", vec![Some(vec![1, 2]), Some(vec![]), None]);
+        let b = int_lists("b", vec![Some(vec![3, 4]), Some(vec![]), Some(vec![5])]);
+
+        let out = a.lst_zip(&[b.clone().into_column()])?;
+        assert_eq!(out.len(), 3);
+        assert_eq!(
+            out.inner_dtype(),
+            &DataType::Struct(vec![
+                Field::new("a".into(), DataType::Int32),
+                Field::new("b".into(), DataType::Int32),
+            ])
+        );
+        assert!(out.get_as_series(2).is_none());
+        assert_eq!(out.get_as_series(1).unwrap().len(), 0);
+
+        let first = out.get_as_series(0).unwrap();
+        let first = first.struct_()?;
+        let fields = first.fields_as_series();
+        assert!(fields[0].equals(&Series::new("a".into(), [1i32, 2])));
+        assert!(fields[1].equals(&Series::new("b".into(), [3i32, 4])));
+
+        let c = int_lists("c", vec![Some(vec![1]), Some(vec![]), Some(vec![2])]);
+        let err = a.lst_zip(&[b.into_column(), c.into_column()]).unwrap_err();
+        assert!(err.to_string().contains("row 0"));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_lst_concat_struct_fields() -> PolarsResult<()> {
+        fn struct_lists(name: &str, fields: [(&str, Series); 2]) -> PolarsResult<ListChunked> {
+            let fields = fields
+                .into_iter()
+                .map(|(name, s)| s.with_name(name.into()))
+                .collect::<Vec<_>>();
+            let st = StructChunked::from_series(PlSmallStr::EMPTY, fields[0].len(), fields.iter())?;
+            Ok(st.into_series().as_list().with_name(name.into()))
+        }
+
+        let a = struct_lists(
+            "a",
+            [
+                ("x", Series::new(PlSmallStr::EMPTY, [1i32, 2])),
+                ("y", Series::new(PlSmallStr::EMPTY, ["a", "b"])),
+            ],
+        )?;
+        let b = struct_lists(
+            "b",
+            [
+                ("x", Series::new(PlSmallStr::EMPTY, [3i64, 4])),
+                ("y", Series::new(PlSmallStr::EMPTY, ["c", "d"])),
+            ],
+        )?;
+        let out = a.lst_concat(&[b.into_column()])?;
+        assert_eq!(
+            out.inner_dtype(),
+            &DataType::Struct(vec![
+                Field::new("x".into(), DataType::Int64),
+                Field::new("y".into(), DataType::String),
+            ])
+        );
+        let first = out.get_as_series(0).unwrap();
+        let x = first.struct_()?.field_by_name("x")?;
+        assert!(x.equals(&Series::new("x".into(), [1i64, 3])));
+
+        let renamed = struct_lists(
+            "c",
+            [
+                ("x", Series::new(PlSmallStr::EMPTY, [3i32, 4])),
+                ("z", Series::new(PlSmallStr::EMPTY, ["c", "d"])),
+            ],
+        )?;
+        let err = a.lst_concat(&[renamed.into_column()]).unwrap_err();
+        assert!(err.to_string().contains("named 'y' in column 'a', but 'z'"));
+
+        let retyped = struct_lists(
+            "d",
+            [
+                ("x", Series::new(PlSmallStr::EMPTY, [3i32, 4])),
+                ("y", Series::new(PlSmallStr::EMPTY, [true, false])),
+            ],
+        )?;
+        let err = a.lst_concat(&[retyped.into_column()]).unwrap_err();
+        assert!(err.to_string().contains("struct field 'y'"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lst_concat_padded() -> PolarsResult<()> {
+        let a = int_lists("a", vec![Some(vec![1, 2]), Some(vec![])]);
+        let b = int_lists("b", vec![Some(vec![3, 4, 5]), None]);
+        let c = int_lists("c", vec![Some(vec![6]), Some(vec![7])]);
+        let others = [b.into_column(), c.into_column()];
+
+        let out = a.lst_concat_padded(&others, PadStrategy::MaxAcrossInputs)?;
+        let expected = Series::new(
+            "a".into(),
+            [
+                Some(Series::new(
+                    PlSmallStr::EMPTY,
+                    [
+                        Some(1i32),
+                        Some(2),
+                        None,
+                        Some(3),
+                        Some(4),
+                        Some(5),
+                        Some(6),
+                        None,
+                        None,
+                    ],
+                )),
+                None,
+            ],
+        );
+        assert!(out.into_series().equals_missing(&expected));
+
+        let out = a.lst_concat_padded(&others, PadStrategy::Fixed(4))?;
+        assert_eq!(out.lst_lengths().get(0), Some(12));
+        assert!(a.lst_concat_padded(&others, PadStrategy::Fixed(2)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lst_join() -> PolarsResult<()> {
+        let ca = Series::new(
+            "a".into(),
+            [
+                Some(Series::new(PlSmallStr::EMPTY, [Some("a"), None, Some("c")])),
+                Some(Series::new(PlSmallStr::EMPTY, [Some("b")])),
+                Some(Series::new(PlSmallStr::EMPTY, Vec::<&str>::new())),
+                None,
+            ],
+        );
+        let ca = ca.list()?;
+
+        let sep = StringChunked::from_slice("sep".into(), &["-"]);
+        let out = ca.lst_join(&sep, false)?;
+        let expected = [None, Some("b"), Some(""), None];
+        assert_eq!(out.iter().collect::<Vec<_>>(), expected);
+
+        let out = ca.lst_join(&sep, true)?;
+        let expected = [Some("a-c"), Some("b"), Some(""), None];
+        assert_eq!(out.iter().collect::<Vec<_>>(), expected);
+
+        let sep = StringChunked::from_slice("sep".into(), &["-", "+", "*", "/"]);
+        let out = ca.lst_join(&sep, true)?;
+        assert_eq!(out.iter().collect::<Vec<_>>(), expected);
+
+        let sep = StringChunked::from_slice("sep".into(), &["-", "+"]);
+        assert!(ca.lst_join(&sep, true).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lst_concat_unique() -> PolarsResult<()> {
+        let a = int_lists("a", vec![Some(vec![1, 2, 1]), Some(vec![5])]);
+        let b = int_lists("b", vec![Some(vec![3, 2]), Some(vec![5, 5])]);
+        let others = [b.into_column()];
+
+        let check = |keep, expected: Vec<Option<Vec<i32>>>| -> PolarsResult<()> {
+            let out = a.lst_concat_unique(&others, keep)?;
+            assert!(
+                out.into_series()
+                    .equals_missing(&int_lists("a", expected).into_series())
+            );
+            Ok(())
+        };
+
+        // the concatenated rows are [1, 2, 1, 3, 2] and [5, 5, 5]
+        check(
+            UniqueKeepStrategy::First,
+            vec![Some(vec![1, 2, 3]), Some(vec![5])],
+        )?;
+        check(
+            UniqueKeepStrategy::Last,
+            vec![Some(vec![1, 3, 2]), Some(vec![5])],
+        )?;
+        check(UniqueKeepStrategy::None, vec![Some(vec![3]), Some(vec![])])?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lst_concat_strict() -> PolarsResult<()> {
+        let a = int_lists("a", vec![Some(vec![1]), Some(vec![2])]);
+        let b = int_lists("b", vec![Some(vec![3]), None]);
+        let c = Series::new("c".into(), [4i32, 5]).into_column();
+        let others = [b.into_column(), c];
+        let out = a.lst_concat_strict(&others)?;
+        assert!(
+            out.into_series()
+                .equals_missing(&a.lst_concat(&others)?.into_series())
+        );
+
+        let wide = Series::new("wide".into(), [4i64, 5]).into_column();
+        let err = a.lst_concat_strict(&[wide]).unwrap_err();
+        assert!(err.to_string().contains("column 'wide' has dtype i64"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lst_concat_uniform_widths() -> PolarsResult<()> {
+        // sliced, so the values of `a` don't start at offset 0
+        let a = int_lists(
+            "a",
+            vec![Some(vec![0, 0]), Some(vec![1, 2]), Some(vec![3, 4])],
+        )
+        .slice(1, 2);
+        let b = int_lists("b", vec![Some(vec![5]), Some(vec![6])]);
+        let c = int_lists("c", vec![Some(vec![]), Some(vec![])]);
+        let d = int_lists("d", vec![Some(vec![9])]);
+        let others = [b.into_column(), c.into_column(), d.into_column()];
+
+        let out = a.lst_concat(&others)?;
+        let expected = int_lists("a", vec![Some(vec![1, 2, 5, 9]), Some(vec![3, 4, 6, 9])]);
+        assert!(out.into_series().equals(&expected.into_series()));
+
+        // rows of differing length take the general path
+        let e = int_lists("e", vec![Some(vec![7]), Some(vec![7, 8])]);
+        let out = a.lst_concat(&[e.into_column()])?;
+        let expected = int_lists("a", vec![Some(vec![1, 2, 7]), Some(vec![3, 4, 7, 8])]);
+        assert!(out.into_series().equals(&expected.into_series()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lst_concat_with_scalars() -> PolarsResult<()> {
+        let a = int_lists("a", vec![Some(vec![1, 2]), Some(vec![3]), Some(vec![])]);
+        let b = Series::new("b".into(), [Some(10i32), None, Some(30)]).into_column();
+
+        let out = a.lst_concat_with_scalars(&[b.clone()], true)?;
+        let expected = Series::new(
+            "a".into(),
+            [
+                Series::new(PlSmallStr::EMPTY, [Some(1i32), Some(2), Some(10)]),
+                Series::new(PlSmallStr::EMPTY, [Some(3i32), None]),
+                Series::new(PlSmallStr::EMPTY, [Some(30i32)]),
+            ],
+        );
+        assert!(out.into_series().equals_missing(&expected));
+
+        let out = a.lst_concat_with_scalars(&[b], false)?;
+        let expected = int_lists(
+            "a",
+            vec![Some(vec![1, 2, 10]), Some(vec![3]), Some(vec![30])],
+        );
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        let c = Column::new_scalar("c".into(), Scalar::new(DataType::Int32, AnyValue::Null), 1);
+        let out = a.lst_concat_with_scalars(&[c], false)?;
+        assert!(out.into_series().equals_missing(&a.into_series()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lst_concat_with_names() -> PolarsResult<()> {
+        let a = int_lists("a", vec![Some(vec![1, 2]), Some(vec![]), None]);
+        let b = int_lists("b", vec![Some(vec![3]), Some(vec![4, 5]), Some(vec![6])]);
+        let c = int_lists("c", vec![Some(vec![]), Some(vec![7]), Some(vec![8])]);
+
+        let (out, tags) = a.lst_concat_with_names(&[b.into_column(), c.into_column()])?;
+        let expected = int_lists("a", vec![Some(vec![1, 2, 3]), Some(vec![4, 5, 7]), None]);
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        let expected = Series::new(
+            "a".into(),
+            [
+                Some(Series::new(PlSmallStr::EMPTY, ["a", "a", "b"])),
+                Some(Series::new(PlSmallStr::EMPTY, ["b", "b", "c"])),
+                None,
+            ],
+        );
+        assert!(tags.into_series().equals_missing(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lst_concat_no_others() -> PolarsResult<()> {
+        let a = int_lists("a", vec![Some(vec![1, 2]), None, Some(vec![3])]);
+
+        let out = a.lst_concat(&[])?;
+        assert!(
+            out.clone()
+                .into_series()
+                .equals_missing(&a.clone().into_series())
+        );
+        // the buffers are shared, not copied
+        let offsets = |ca: &ListChunked| {
+            ca.downcast_iter()
+                .next()
+                .unwrap()
+                .offsets()
+                .as_slice()
+                .as_ptr()
+        };
+        assert_eq!(offsets(&out), offsets(&a));
+
+        let out = a.lst_concat_with_null_behavior(&[], ListConcatNullBehavior::TreatAsEmpty)?;
+        let expected = int_lists("a", vec![Some(vec![1, 2]), Some(vec![]), Some(vec![3])]);
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-array")]
+    fn test_lst_concat_arrays() -> PolarsResult<()> {
+        fn array_lists(name: &str, rows: Vec<Vec<Vec<f32>>>, width: usize) -> ListChunked {
+            let dtype = DataType::Array(Box::new(DataType::Float32), width);
+            let rows = rows
+                .into_iter()
+                .map(|row| {
+                    let row = row
+                        .into_iter()
+                        .map(|arr| Series::new(PlSmallStr::EMPTY, arr))
+                        .collect::<Vec<_>>();
+                    Series::new(PlSmallStr::EMPTY, row).cast(&dtype).unwrap()
+                })
+                .collect::<Vec<_>>();
+            Series::new(name.into(), rows).list().unwrap().clone()
+        }
+
+        let a = array_lists("a", vec![vec![vec![1.0; 4]], vec![]], 4);
+        let b = array_lists(
+            "b",
+            vec![vec![vec![2.0; 4]], vec![vec![3.0; 4], vec![4.0; 4]]],
+            4,
+        );
+        let out = a.lst_concat(&[b.into_column()])?;
+        assert_eq!(
+            out.inner_dtype(),
+            &DataType::Array(Box::new(DataType::Float32), 4)
+        );
+        let expected = array_lists(
+            "a",
+            vec![
+                vec![vec![1.0; 4], vec![2.0; 4]],
+                vec![vec![3.0; 4], vec![4.0; 4]],
+            ],
+            4,
+        );
+        assert!(out.into_series().equals(&expected.into_series()));
+
+        let c = array_lists("c", vec![vec![vec![2.0; 3]], vec![]], 3);
+        let err = a.lst_concat(&[c.into_column()]).unwrap_err();
+        assert!(err.to_string().contains("column 'c' has width 3"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lst_concat_with_chunk_size() -> PolarsResult<()> {
+        let a = int_lists("a", (0..5).map(|i| Some(vec![i])).collect());
+        let b = int_lists("b", (0..5).map(|i| Some(vec![i; i as usize])).collect());
+        let others = [b.into_column()];
+        let expected = a.lst_concat(&others)?;
+
+        for (chunk_size, chunk_lengths) in [
+            (None, vec![5]),
+            (Some(2), vec![2, 2, 1]),
+            (Some(10), vec![5]),
+        ] {
+            let out = a.lst_concat_with_chunk_size(&others, chunk_size)?;
+            assert_eq!(out.chunk_lengths().collect::<Vec<_>>(), chunk_lengths);
+            assert!(
+                out.into_series()
+                    .equals_missing(&expected.clone().into_series())
+            );
+        }
+
+        assert!(a.lst_concat_with_chunk_size(&others, Some(0)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lst_concat_iter() -> PolarsResult<()> {
+        let a = int_lists("a", vec![Some(vec![1]), Some(vec![2, 3]), None]);
+        let b = int_lists("b", vec![Some(vec![4]), Some(vec![]), Some(vec![5])]);
+        let c = Series::new("c".into(), [6i64, 7, 8]).into_column();
+        let d = Series::new("d".into(), [9i8]).into_column();
+        let e = int_lists("e", vec![None, Some(vec![10]), Some(vec![11])]);
+
+        for others in [
+            vec![],
+            vec![b.clone().into_column()],
+            vec![b.into_column(), c.clone()],
+            vec![d.clone(), c],
+            vec![d, e.into_column()],
+        ] {
+            let expected = a.lst_concat(&others)?;
+            let out = a.lst_concat_iter(others)?;
+            assert_eq!(out.dtype(), expected.dtype());
+            assert!(out.into_series().equals_missing(&expected.into_series()));
+        }
+
+        Ok(())
+    }
+}
@@ -1,22 +1,134 @@
-use arrow::array::FixedSizeListArray;
+use std::borrow::Cow;
+
+use arrow::array::builder::{ShareStrategy, make_builder};
+use arrow::array::{Array, FixedSizeListArray};
 use arrow::compute::utils::combine_validities_and;
 use polars_compute::horizontal_flatten::horizontal_flatten_unchecked;
 use polars_core::prelude::{ArrayChunked, Column, CompatLevel, DataType, IntoColumn};
 use polars_core::series::Series;
-use polars_error::{PolarsResult, polars_bail};
+use polars_core::utils::{SuperTypeFlags, try_get_supertype_with_options};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 use polars_utils::pl_str::PlSmallStr;
 
-/// Note: The caller must ensure all columns in `args` have the same type.
+use crate::chunked_array::list::ListNameSpaceImpl;
+
+/// How [`concat_arr_with_width_policy`] deals with inputs of differing widths.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum WidthPolicy {
+    /// Every input keeps its own width. The output width is the sum of the input widths.
+    #[default]
+    Strict,
+    /// Inputs are right-padded with nulls to the width of the widest input. The output width is
+    /// the number of inputs times that width.
+    PadToMax,
+}
+
+/// Determine the output dtype of a `concat_arr` operation. Also performs validation to ensure input
+/// dtypes are compatible.
+///
+/// The inner dtype of the output is the supertype of the (inner) dtypes of all inputs. Primitives
+/// are not turned into strings to find this supertype.
+///
+/// `List` inputs are single elements of the output unless `list_width` is given, in which case
+/// they are flattened into `list_width` elements, see [`concat_arr`].
+pub fn concat_arr_output_dtype(
+    inputs: &mut dyn ExactSizeIterator<Item = (&str, &DataType)>,
+    list_width: Option<usize>,
+) -> PolarsResult<DataType> {
+    #[allow(clippy::len_zero)]
+    if inputs.len() == 0 {
+        // should not be reachable - we did not set ALLOW_EMPTY_INPUTS
+        panic!();
+    }
+
+    let mut inputs = inputs.map(|(name, dtype)| {
+        let (inner_dtype, width) = match (dtype, list_width) {
+            (DataType::Array(inner, width), _) => (inner.as_ref(), *width),
+            (DataType::List(inner), Some(list_width)) => (inner.as_ref(), list_width),
+            (dt, _) => (dt, 1),
+        };
+        (name, dtype, inner_dtype, width)
+    });
+    let (first_name, first_dtype, first_inner_dtype, mut out_width) = inputs.next().unwrap();
+    let mut out_inner_dtype = first_inner_dtype.clone();
+
+    for (col_name, dtype, inner_dtype, width) in inputs {
+        out_width += width;
+
+        if inner_dtype == &out_inner_dtype {
+            continue;
+        }
+        let options = SuperTypeFlags::empty().into();
+        let Ok(supertype) = try_get_supertype_with_options(&out_inner_dtype, inner_dtype, options)
+        else {
+            polars_bail!(
+                SchemaMismatch:
+                "concat_arr dtype mismatch: expected {} or array[{}] dtype to be compatible with \
+                dtype of first input column (name: {}, dtype: {}), got {} instead for column {}",
+                out_inner_dtype, out_inner_dtype, first_name, first_dtype, dtype, col_name,
+            )
+        };
+        out_inner_dtype = supertype;
+    }
+
+    Ok(DataType::Array(Box::new(out_inner_dtype), out_width))
+}
+
+/// Inputs whose (inner) dtype differs from the inner dtype of `dtype` are cast to it, see
+/// [`concat_arr_output_dtype`].
+///
+/// `List` columns are single elements of the output, like any other non-array column. If
+/// `list_width` is given they are flattened instead, in which case every non-null row of a `List`
+/// column must have `list_width` elements.
 ///
 /// # Panics
 /// Panics if
 /// * `args` is empty
 /// * `dtype` is not a `DataType::Array`
-pub fn concat_arr(args: &[Column], dtype: &DataType) -> PolarsResult<Column> {
+pub fn concat_arr(
+    args: &[Column],
+    dtype: &DataType,
+    list_width: Option<usize>,
+) -> PolarsResult<Column> {
     let DataType::Array(inner_dtype, width) = dtype else {
         panic!("{}", dtype);
     };
 
+    let args: Cow<'_, [Column]> = match list_width {
+        Some(list_width) if args.iter().any(|c| c.dtype().is_list()) => Cow::Owned(
+            args.iter()
+                .map(|c| match c.dtype() {
+                    DataType::List(_) => list_to_array_with_width(c, list_width),
+                    _ => Ok(c.clone()),
+                })
+                .collect::<PolarsResult<Vec<_>>>()?,
+        ),
+        _ => Cow::Borrowed(args),
+    };
+    let args = args.as_ref();
+
+    let needs_cast = |c: &Column| match c.dtype() {
+        DataType::Array(inner, _) => inner != inner_dtype,
+        dt => dt != inner_dtype.as_ref(),
+    };
+    let args: Cow<'_, [Column]> = if args.iter().any(needs_cast) {
+        Cow::Owned(
+            args.iter()
+                .map(|c| match c.dtype() {
+                    DataType::Array(inner, width) if inner != inner_dtype => {
+                        c.cast(&DataType::Array(inner_dtype.clone(), *width))
+                    },
+                    DataType::Array(_, _) => Ok(c.clone()),
+                    dt if dt != inner_dtype.as_ref() => c.cast(inner_dtype),
+                    _ => Ok(c.clone()),
+                })
+                .collect::<PolarsResult<Vec<_>>>()?,
+        )
+    } else {
+        Cow::Borrowed(args)
+    };
+    let args = args.as_ref();
+
     let inner_dtype = inner_dtype.as_ref();
     let width = *width;
 
@@ -80,7 +192,12 @@ pub fn concat_arr(args: &[Column], dtype: &DataType) -> PolarsResult<Column> {
         })
         .unzip();
 
-    assert_eq!(calculated_width, width);
+    polars_ensure!(
+        calculated_width == width,
+        ShapeMismatch:
+        "concat_arr: total width of the inputs ({}) did not match the width of the output dtype ({})",
+        calculated_width, width,
+    );
 
     if mismatch_height.1 != output_height {
         polars_bail!(
@@ -140,3 +257,412 @@ pub fn concat_arr(args: &[Column], dtype: &DataType) -> PolarsResult<Column> {
 
     Ok(out)
 }
+
+/// Same as [`concat_arr`], but the output dtype is derived from the inputs with
+/// [`concat_arr_output_dtype`]. With [`WidthPolicy::PadToMax`] the inputs are first padded to the
+/// width of the widest input, non-array inputs have width 1.
+///
+/// `List` inputs are handled according to `list_width`, see [`concat_arr`].
+///
+/// # Panics
+/// Panics if `args` is empty.
+pub fn concat_arr_with_width_policy(
+    args: &[Column],
+    width_policy: WidthPolicy,
+    list_width: Option<usize>,
+) -> PolarsResult<Column> {
+    let args = args
+        .iter()
+        .map(|c| match (c.dtype(), list_width) {
+            (DataType::List(_), Some(list_width)) => list_to_array_with_width(c, list_width),
+            _ => Ok(c.clone()),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let args = match width_policy {
+        WidthPolicy::Strict => args,
+        WidthPolicy::PadToMax => pad_to_max_width(&args)?,
+    };
+
+    let dtype = concat_arr_output_dtype(
+        &mut args.iter().map(|c| (c.name().as_str(), c.dtype())),
+        None,
+    )?;
+    concat_arr(&args, &dtype, None)
+}
+
+/// Same as [`concat_arr`], but produces a `List` column, so the inputs don't need to have a
+/// fixed width.
+///
+/// The inner dtype of the output is the supertype of the (inner) dtypes of all inputs, resolved
+/// the same way as in [`concat_arr_output_dtype`]. Inputs with a different (inner) dtype are cast
+/// to it.
+///
+/// # Panics
+/// Panics if `args` is empty.
+pub fn concat_to_list(args: &[Column]) -> PolarsResult<Column> {
+    let inner_dtype_of = |c: &Column| match c.dtype() {
+        DataType::List(inner) | DataType::Array(inner, _) => inner.as_ref().clone(),
+        dt => dt.clone(),
+    };
+
+    let first = &args[0];
+    let mut inner_dtype = inner_dtype_of(first);
+    for c in &args[1..] {
+        let c_inner_dtype = inner_dtype_of(c);
+        if c_inner_dtype == inner_dtype {
+            continue;
+        }
+        let options = SuperTypeFlags::empty().into();
+        let Ok(supertype) = try_get_supertype_with_options(&inner_dtype, &c_inner_dtype, options)
+        else {
+            polars_bail!(
+                SchemaMismatch:
+                "concat_to_list dtype mismatch: expected {} or list[{}] dtype to be compatible \
+                with dtype of first input column (name: {}, dtype: {}), got {} instead for \
+                column {}",
+                inner_dtype, inner_dtype, first.name(), first.dtype(), c.dtype(), c.name(),
+            )
+        };
+        inner_dtype = supertype;
+    }
+    let dtype = DataType::List(Box::new(inner_dtype.clone()));
+
+    let output_height = args
+        .iter()
+        .map(|c| c.len())
+        .find(|len| *len != 1)
+        .unwrap_or(1);
+
+    let mut args = args
+        .iter()
+        .map(|c| match c.dtype() {
+            DataType::List(_) | DataType::Array(_, _) => c.cast(&dtype),
+            // Reshape to (-1, 1), like `concat_arr` does for non-array inputs.
+            _ => Ok(c
+                .cast(&inner_dtype)?
+                .as_materialized_series_maintain_scalar()
+                .as_list()
+                .into_column()),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    debug_assert!(args.iter().all(|c| c.dtype() == &dtype));
+
+    let first = args.remove(0);
+    let first = if first.len() == 1 && output_height != 1 {
+        first.new_from_index(0, output_height)
+    } else {
+        first
+    };
+    let out = first.list()?.lst_concat(&args)?;
+    debug_assert_eq!(out.inner_dtype(), &inner_dtype);
+    Ok(out.into_column())
+}
+
+/// Right-pad all `args` with nulls to the width of the widest one.
+fn pad_to_max_width(args: &[Column]) -> PolarsResult<Vec<Column>> {
+    let width_of = |c: &Column| match c.dtype() {
+        DataType::Array(_, width) => *width,
+        _ => 1,
+    };
+    let max_width = args.iter().map(width_of).max().unwrap_or(0);
+
+    args.iter()
+        .map(|c| {
+            let width = width_of(c);
+            if width == max_width {
+                return Ok(c.clone());
+            }
+
+            // Keep scalars at unit length, `concat_arr` broadcasts them.
+            let s = c.as_materialized_series_maintain_scalar();
+            let (inner_dtype, values, validity) = match s.dtype() {
+                DataType::Array(inner, _) => {
+                    let ca = s.array()?.rechunk();
+                    let arr = ca.downcast_as_array();
+                    (
+                        inner.as_ref().clone(),
+                        arr.values().clone(),
+                        arr.validity().cloned(),
+                    )
+                },
+                dt => (dt.clone(), s.rechunk().into_chunks()[0].clone(), None),
+            };
+
+            let mut builder = make_builder(values.dtype());
+            builder.reserve(s.len() * max_width);
+            for row in 0..s.len() {
+                builder.subslice_extend(values.as_ref(), row * width, width, ShareStrategy::Always);
+                builder.extend_nulls(max_width - width);
+            }
+            let values = builder.freeze_reset();
+
+            let arrow_dtype =
+                FixedSizeListArray::default_datatype(values.dtype().clone(), max_width);
+            let arr = FixedSizeListArray::new(arrow_dtype, s.len(), values, validity);
+            let out_dtype = DataType::Array(Box::new(inner_dtype), max_width);
+            // SAFETY: the values are the (physical) values of the input, which has the same inner
+            // dtype.
+            let out = unsafe {
+                Series::from_chunks_and_dtype_unchecked(
+                    s.name().clone(),
+                    vec![arr.boxed()],
+                    &out_dtype,
+                )
+            }
+            .into_column();
+
+            Ok(if out.len() != c.len() {
+                out.new_from_index(0, c.len())
+            } else {
+                out
+            })
+        })
+        .collect()
+}
+
+/// Convert a `List` column to an `Array` column of `width`, erroring on the first non-null row
+/// that does not have `width` elements.
+fn list_to_array_with_width(c: &Column, width: usize) -> PolarsResult<Column> {
+    let ca = c.list()?;
+
+    let mut row_offset = 0;
+    for arr in ca.downcast_iter() {
+        for (i, length) in arr.offsets().lengths().enumerate() {
+            polars_ensure!(
+                length == width || !arr.is_valid(i),
+                ShapeMismatch:
+                "concat_arr: list column '{}' has length {} at row {}, expected length {}",
+                c.name(), length, row_offset + i, width,
+            );
+        }
+        row_offset += arr.len();
+    }
+
+    c.cast(&DataType::Array(Box::new(ca.inner_dtype().clone()), width))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int_lists(name: &str, rows: &[&[i32]]) -> Series {
+        let rows = rows
+            .iter()
+            .map(|row| Series::new(PlSmallStr::EMPTY, *row))
+            .collect::<Vec<_>>();
+        Series::new(name.into(), rows)
+    }
+
+    #[test]
+    fn test_concat_arr_mixed_list_and_array() -> PolarsResult<()> {
+        let a = int_lists("a", &[&[1, 2], &[3, 4]]).into_column();
+        let b = int_lists("b", &[&[5], &[6]])
+            .cast(&DataType::Array(Box::new(DataType::Int32), 1))?
+            .into_column();
+
+        let args = [a.clone(), b];
+        let dtype = concat_arr_output_dtype(
+            &mut args.iter().map(|c| (c.name().as_str(), c.dtype())),
+            Some(2),
+        )?;
+        assert_eq!(dtype, DataType::Array(Box::new(DataType::Int32), 3));
+        let out = concat_arr(&args, &dtype, Some(2))?;
+        let expected = int_lists("a", &[&[1, 2, 5], &[3, 4, 6]]).cast(&dtype)?;
+        assert!(out.as_materialized_series().equals(&expected));
+
+        let ragged = int_lists("c", &[&[1, 2], &[3]]).into_column();
+        let dtype = DataType::Array(Box::new(DataType::Int32), 4);
+        let err = concat_arr(&[a.clone(), ragged], &dtype, Some(2)).unwrap_err();
+        assert!(err.to_string().contains("length 1 at row 1"));
+
+        // Without a list width, lists are elements of the output.
+        let c = int_lists("c", &[&[7], &[8, 9]]).into_column();
+        let args = [a, c];
+        let dtype = concat_arr_output_dtype(
+            &mut args.iter().map(|c| (c.name().as_str(), c.dtype())),
+            None,
+        )?;
+        assert_eq!(
+            dtype,
+            DataType::Array(Box::new(DataType::List(Box::new(DataType::Int32))), 2)
+        );
+        let out = concat_arr(&args, &dtype, None)?;
+        let row = out.array()?.get_as_series(1).unwrap();
+        assert!(row.equals(&int_lists("", &[&[3, 4], &[8, 9]])));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_arr_no_nulls_fast_path() -> PolarsResult<()> {
+        let a = int_lists("a", &[&[1, 2], &[3, 4], &[5, 6]]);
+        let b = Series::new("b".into(), [7i32, 8, 9]);
+        let dtype = DataType::Array(Box::new(DataType::Int32), 3);
+        let expected = int_lists("a", &[&[1, 2, 7], &[3, 4, 8], &[5, 6, 9]]).cast(&dtype)?;
+
+        let out = concat_arr(
+            &[a.clone().into_column(), b.clone().into_column()],
+            &dtype,
+            Some(2),
+        )?;
+        assert!(out.as_materialized_series().equals(&expected));
+
+        let a = a.cast(&DataType::List(Box::new(DataType::Boolean)))?;
+        let b = b.cast(&DataType::Boolean)?;
+        let dtype = DataType::Array(Box::new(DataType::Boolean), 3);
+        let out = concat_arr(&[a.into_column(), b.into_column()], &dtype, Some(2))?;
+        assert!(out.as_materialized_series().equals(&expected.cast(&dtype)?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_to_list() -> PolarsResult<()> {
+        let a = int_lists("a", &[&[1, 2], &[3, 4]]).into_column();
+        let b = Series::new("b".into(), [5i32, 6]).into_column();
+
+        let arr_dtype = DataType::Array(Box::new(DataType::Int32), 3);
+        let expected = concat_arr(&[a.clone(), b.clone()], &arr_dtype, Some(2))?;
+        let out = concat_to_list(&[a.clone(), b.clone()])?;
+        assert!(
+            out.cast(&arr_dtype)?
+                .as_materialized_series()
+                .equals(expected.as_materialized_series())
+        );
+
+        // variable widths are fine for lists
+        let ragged = int_lists("c", &[&[7], &[8, 9]]).into_column();
+        let out = concat_to_list(&[a.clone(), ragged, b])?;
+        let expected = int_lists("a", &[&[1, 2, 7, 5], &[3, 4, 8, 9, 6]]);
+        assert!(out.as_materialized_series().equals(&expected));
+
+        // mixed inner dtypes are cast to their supertype
+        let c = Series::new("c".into(), [5i64, 6]).into_column();
+        let d = int_lists("d", &[&[7], &[8, 9]])
+            .cast(&DataType::List(Box::new(DataType::Int64)))?
+            .into_column();
+        let out = concat_to_list(&[a.clone(), c, d])?;
+        let expected = int_lists("a", &[&[1, 2, 5, 7], &[3, 4, 6, 8, 9]])
+            .cast(&DataType::List(Box::new(DataType::Int64)))?;
+        assert_eq!(out.dtype(), expected.dtype());
+        assert!(out.as_materialized_series().equals(&expected));
+
+        let s = Series::new("s".into(), ["x", "y"]).into_column();
+        let err = concat_to_list(&[a, s]).unwrap_err();
+        assert!(err.to_string().contains("concat_to_list dtype mismatch"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_arr_supertype() -> PolarsResult<()> {
+        let a = Series::new("a".into(), [1i32, 2]).into_column();
+        let b = Series::new("b".into(), [0.5f64, 1.5]).into_column();
+        let c = Series::new("c".into(), [3i64, 4]).into_column();
+        let args = [a, b, c];
+
+        let dtype = concat_arr_output_dtype(
+            &mut args.iter().map(|c| (c.name().as_str(), c.dtype())),
+            None,
+        )?;
+        assert_eq!(dtype, DataType::Array(Box::new(DataType::Float64), 3));
+
+        let out = concat_arr(&args, &dtype, None)?;
+        let expected = Series::new(
+            "a".into(),
+            [
+                Series::new(PlSmallStr::EMPTY, [1.0f64, 0.5, 3.0]),
+                Series::new(PlSmallStr::EMPTY, [2.0f64, 1.5, 4.0]),
+            ],
+        )
+        .cast(&dtype)?;
+        assert!(out.as_materialized_series().equals(&expected));
+
+        let s = Series::new("s".into(), ["x", "y"]).into_column();
+        let args = [args[0].clone(), s];
+        let err = concat_arr_output_dtype(
+            &mut args.iter().map(|c| (c.name().as_str(), c.dtype())),
+            None,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("concat_arr dtype mismatch"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_arr_misaligned_chunks() -> PolarsResult<()> {
+        let mut a = int_lists("a", &[&[1, 2]]);
+        a.append(&int_lists("a", &[&[3, 4], &[5, 6]]))?;
+        let mut b = Series::new("b".into(), [7i32, 8]);
+        b.append(&Series::new("b".into(), [9i32]))?;
+        assert_eq!(a.n_chunks(), 2);
+        assert_eq!(b.n_chunks(), 2);
+
+        let dtype = DataType::Array(Box::new(DataType::Int32), 3);
+        let out = concat_arr(&[a.into_column(), b.into_column()], &dtype, None)?;
+        let expected = int_lists("a", &[&[1, 2, 7], &[3, 4, 8], &[5, 6, 9]]).cast(&dtype)?;
+        assert!(out.as_materialized_series().equals(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_arr_broadcast_unit_length() -> PolarsResult<()> {
+        let dtype = DataType::Array(Box::new(DataType::Int32), 3);
+        let a = int_lists("a", &[&[1, 2], &[3, 4], &[5, 6]])
+            .cast(&DataType::Array(Box::new(DataType::Int32), 2))?
+            .into_column();
+        let b = Series::new("b".into(), [0i32]).into_column();
+        let scalar = Column::new_scalar("c".into(), 0i32.into(), 1);
+
+        let expected = int_lists("a", &[&[1, 2, 0], &[3, 4, 0], &[5, 6, 0]]).cast(&dtype)?;
+        for unit in [b, scalar] {
+            let out = concat_arr(&[a.clone(), unit], &dtype, None)?;
+            assert!(out.as_materialized_series().equals(&expected));
+        }
+
+        let c = Series::new("c".into(), [0i32, 1]).into_column();
+        let err = concat_arr(&[a, c], &dtype, None).unwrap_err();
+        assert!(err.to_string().contains("did not match length"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_arr_pad_to_max() -> PolarsResult<()> {
+        let a = int_lists("a", &[&[1, 2, 3], &[4, 5, 6]])
+            .cast(&DataType::Array(Box::new(DataType::Int32), 3))?
+            .into_column();
+        let b = int_lists("b", &[&[7, 8, 9, 10, 11], &[12, 13, 14, 15, 16]])
+            .cast(&DataType::Array(Box::new(DataType::Int32), 5))?
+            .into_column();
+        let args = [a, b];
+
+        let dtype = DataType::Array(Box::new(DataType::Int32), 10);
+        let out = concat_arr_with_width_policy(&args, WidthPolicy::PadToMax, None)?;
+        assert_eq!(out.dtype(), &dtype);
+
+        let rows = [
+            [Some(1), Some(2), Some(3), None, None],
+            [Some(4), Some(5), Some(6), None, None],
+        ]
+        .into_iter()
+        .zip([[7, 8, 9, 10, 11], [12, 13, 14, 15, 16]])
+        .map(|(a, b)| {
+            let row = a.into_iter().chain(b.map(Some)).collect::<Vec<_>>();
+            Series::new(PlSmallStr::EMPTY, row)
+        })
+        .collect::<Vec<_>>();
+        let expected = Series::new("a".into(), rows).cast(&dtype)?;
+        assert!(out.as_materialized_series().equals_missing(&expected));
+
+        // The default keeps the input widths.
+        let err = concat_arr(&args, &dtype, None).unwrap_err();
+        assert!(err.to_string().contains("total width of the inputs (8)"));
+        let out = concat_arr_with_width_policy(&args, WidthPolicy::Strict, None)?;
+        assert_eq!(out.dtype(), &DataType::Array(Box::new(DataType::Int32), 8));
+
+        Ok(())
+    }
+}
@@ -1,3 +1,6 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
 use arrow::array::{Array, BinaryViewArray, MutableBinaryViewArray, Utf8ViewArray, View};
 use arrow::bitmap::{Bitmap, BitmapBuilder};
 use arrow::datatypes::{ArrowDataType, PhysicalType};
@@ -11,6 +14,7 @@ use super::{Filter, PredicateFilter, dictionary_encoded};
 use crate::parquet::encoding::{Encoding, delta_byte_array, delta_length_byte_array, hybrid_rle};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::page::{DataPage, DictPage, split_buffer};
+use crate::parquet::schema::types::PrimitiveType;
 use crate::read::deserialize::utils::{self, Decoded};
 use crate::read::expr::{ParquetScalar, SpecializedParquetColumnExpr};
 
@@ -76,16 +80,85 @@ enum EqualsOneOfState {
     Set(PlIndexSet<Box<[u8]>>, Vec<View>),
 }
 
+/// Options for decoding binary and string columns.
+#[derive(Debug, Clone)]
+pub struct BinaryDecodeOptions {
+    /// Whether to verify that string columns are valid UTF-8. Only turn this off for trusted
+    /// files, e.g. files that were just written by Polars. On by default.
+    pub verify_utf8: bool,
+    /// Whether to check that a fully decoded plain page holds no more values than its header
+    /// declares. On by default in debug builds.
+    pub check_num_values: bool,
+    /// Error on values that are longer than this many bytes, before they are copied. No limit by
+    /// default.
+    pub max_value_len: Option<usize>,
+    /// Error on the first null of a page instead of decoding it, e.g. to check that a column that
+    /// should not have nulls really has none. Off by default.
+    pub error_on_null: bool,
+    /// Progress counters that are updated after every decoded page, so a caller holding the other
+    /// end of the `Arc` can poll them while the column is decoded. Not tracked by default.
+    pub progress: Option<Arc<BinaryDecodeProgress>>,
+}
+
+impl Default for BinaryDecodeOptions {
+    fn default() -> Self {
+        Self {
+            verify_utf8: true,
+            check_num_values: cfg!(debug_assertions),
+            max_value_len: None,
+            error_on_null: false,
+            progress: None,
+        }
+    }
+}
+
+/// Progress of decoding a binary or string column, e.g. to drive a progress bar.
+#[derive(Debug, Default)]
+pub struct BinaryDecodeProgress {
+    rows_decoded: AtomicUsize,
+    bytes_decoded: AtomicUsize,
+    last_encoding: Mutex<Option<Encoding>>,
+}
+
+impl BinaryDecodeProgress {
+    /// The number of rows decoded so far, nulls included.
+    pub fn rows_decoded(&self) -> usize {
+        self.rows_decoded.load(Ordering::Relaxed)
+    }
+
+    /// The number of value bytes decoded so far.
+    pub fn bytes_decoded(&self) -> usize {
+        self.bytes_decoded.load(Ordering::Relaxed)
+    }
+
+    /// The encoding of the most recently decoded data page, if any page was decoded yet.
+    /// Dictionary encoded pages are reported as [`Encoding::RleDictionary`].
+    pub fn last_encoding(&self) -> Option<Encoding> {
+        *self.last_encoding.lock().unwrap()
+    }
+
+    fn record_page(&self, encoding: Encoding, rows: usize, bytes: usize) {
+        *self.last_encoding.lock().unwrap() = Some(encoding);
+        self.rows_decoded.fetch_add(rows, Ordering::Relaxed);
+        self.bytes_decoded.fetch_add(bytes, Ordering::Relaxed);
+    }
+}
+
 pub(crate) struct BinViewDecoder {
     is_string: bool,
+    options: BinaryDecodeOptions,
     equals_one_of_state: Option<Box<EqualsOneOfState>>,
+    /// The column that is decoded, used to point decoding errors to that column.
+    column: Option<PrimitiveType>,
 }
 
 impl BinViewDecoder {
     pub fn new(is_string: bool) -> Self {
         Self {
             is_string,
+            options: BinaryDecodeOptions::default(),
             equals_one_of_state: None,
+            column: None,
         }
     }
 
@@ -93,6 +166,186 @@ impl BinViewDecoder {
         Self::new(true)
     }
 
+    pub fn with_column(mut self, type_: &PrimitiveType) -> Self {
+        self.column = Some(type_.clone());
+        self
+    }
+
+    pub fn with_options(mut self, options: &BinaryDecodeOptions) -> Self {
+        self.options = options.clone();
+        self
+    }
+
+    /// Whether the decoded values have to be checked to be valid UTF-8.
+    fn verify_utf8(&self) -> bool {
+        self.is_string && self.options.verify_utf8
+    }
+
+    /// Add the column of this decoder to an out-of-spec error.
+    #[cold]
+    fn with_column_context(&self, err: ParquetError) -> ParquetError {
+        match (err, &self.column) {
+            (ParquetError::OutOfSpec(msg), Some(column)) => ParquetError::OutOfSpec(format!(
+                "{msg} (column '{}', physical type {:?})",
+                column.field_info.name, column.physical_type
+            )),
+            (err, _) => err,
+        }
+    }
+
+    fn decode_state(
+        &mut self,
+        mut state: utils::State<'_, Self>,
+        decoded: &mut DecodedStateTuple,
+        filter: Option<super::Filter>,
+    ) -> ParquetResult<()> {
+        if self.options.error_on_null
+            && let Some(page_validity) = &state.page_validity
+            && page_validity.unset_bits() > 0
+        {
+            return Err(null_value_err(page_validity.leading_ones()));
+        }
+
+        let verify_utf8 = self.verify_utf8();
+        let check_num_values = self.options.check_num_values;
+        let max_value_len = self.options.max_value_len;
+        let equals_one_of_state = self.initialize_decode_equals_one_of_state(&mut decoded.0);
+        match state.translation {
+            StateTranslation::Plain(iter) => {
+                if let Some(max_value_len) = max_value_len {
+                    verify_plain_value_lens(iter.values, max_value_len)?;
+                }
+
+                decode_plain(
+                    iter.values,
+                    iter.max_num_values,
+                    &mut decoded.0,
+                    state.is_optional,
+                    &mut decoded.1,
+                    state.page_validity.as_ref(),
+                    filter,
+                    equals_one_of_state,
+                    check_num_values,
+                    verify_utf8,
+                )
+            },
+            StateTranslation::Dictionary(ref mut indexes) => {
+                let dict = state.dict.unwrap();
+
+                let start_length = decoded.0.views().len();
+
+                dictionary_encoded::decode_dict(
+                    indexes.clone(),
+                    dict.views().as_slice(),
+                    state.is_optional,
+                    state.page_validity.as_ref(),
+                    filter,
+                    &mut decoded.1,
+                    unsafe { decoded.0.views_mut() },
+                )?;
+
+                let total_length: usize = decoded
+                    .0
+                    .views()
+                    .iter()
+                    .skip(start_length)
+                    .map(|view| view.length as usize)
+                    .sum();
+                unsafe {
+                    decoded
+                        .0
+                        .set_total_bytes_len(decoded.0.total_bytes_len() + total_length);
+                }
+
+                Ok(())
+            },
+            StateTranslation::DeltaLengthByteArray(decoder, _vec) => {
+                let values = decoder.values;
+                let lengths = decoder.lengths.collect::<Vec<i64>>()?;
+                verify_delta_lengths(&lengths, values.len())?;
+                if let Some(max_value_len) = max_value_len
+                    && let Some(idx) = lengths.iter().position(|&l| l as usize > max_value_len)
+                {
+                    return Err(value_too_long_err(
+                        idx,
+                        lengths[idx] as usize,
+                        max_value_len,
+                    ));
+                }
+
+                if verify_utf8 {
+                    let mut none_starting_with_continuation_byte = true;
+                    let mut offset = 0;
+                    for length in &lengths {
+                        none_starting_with_continuation_byte &=
+                            *length == 0 || values[offset] & 0xC0 != 0x80;
+                        offset += *length as usize;
+                    }
+
+                    if !none_starting_with_continuation_byte
+                        || simdutf8::basic::from_utf8(&values[..offset]).is_err()
+                    {
+                        let mut offset = 0;
+                        return Err(locate_invalid_utf8_err(lengths.iter().map(|&length| {
+                            let value = &values[offset..offset + length as usize];
+                            offset += length as usize;
+                            value
+                        })));
+                    }
+                }
+
+                let mut i = 0;
+                let mut offset = 0;
+                unspecialized_decode(
+                    lengths.len(),
+                    || {
+                        let length = lengths[i] as usize;
+
+                        let value = &values[offset..offset + length];
+
+                        i += 1;
+                        offset += length;
+
+                        Ok(value)
+                    },
+                    filter,
+                    state.page_validity,
+                    state.is_optional,
+                    &mut decoded.1,
+                    &mut decoded.0,
+                )
+            },
+            StateTranslation::DeltaBytes(mut decoder) => {
+                let mut idx = 0;
+
+                unspecialized_decode(
+                    decoder.len(),
+                    || {
+                        let value = decoder.next().unwrap()?;
+
+                        if let Some(max_value_len) = max_value_len
+                            && value.len() > max_value_len
+                        {
+                            return Err(value_too_long_err(idx, value.len(), max_value_len));
+                        }
+
+                        if verify_utf8 && let Err(err) = simdutf8::compat::from_utf8(&value[..]) {
+                            return Err(invalid_utf8_at_err(idx, err));
+                        }
+                        idx += 1;
+
+                        Ok(value)
+                    },
+                    filter,
+                    state.page_validity,
+                    state.is_optional,
+                    &mut decoded.1,
+                    &mut decoded.0,
+                )
+            },
+        }
+    }
+
     fn initialize_predicate_equals_one_of_state(
         &mut self,
         needles: &[ParquetScalar],
@@ -174,6 +427,17 @@ pub(crate) enum StateTranslation<'a> {
     DeltaBytes(delta_byte_array::Decoder<'a>),
 }
 
+impl StateTranslation<'_> {
+    fn encoding(&self) -> Encoding {
+        match self {
+            Self::Plain(_) => Encoding::Plain,
+            Self::Dictionary(_) => Encoding::RleDictionary,
+            Self::DeltaLengthByteArray(..) => Encoding::DeltaLengthByteArray,
+            Self::DeltaBytes(_) => Encoding::DeltaByteArray,
+        }
+    }
+}
+
 impl utils::Decoded for DecodedStateTuple {
     fn len(&self) -> usize {
         self.0.len()
@@ -202,6 +466,7 @@ fn decode_plain(
     filter: Option<Filter>,
 
     equals_one_of_state: Option<&EqualsOneOfState>,
+    check_num_values: bool,
     verify_utf8: bool,
 ) -> ParquetResult<()> {
     if is_optional {
@@ -249,15 +514,28 @@ fn decode_plain(
     let page_validity = constrain_page_validity(max_num_values, page_validity, filter.as_ref());
 
     match (filter, page_validity) {
-        (None, None) => required::decode(max_num_values, values, None, target, verify_utf8),
-        (Some(Filter::Range(rng)), None) if rng.start == 0 => {
-            required::decode(max_num_values, values, Some(rng.end), target, verify_utf8)
-        },
+        (None, None) => required::decode(
+            max_num_values,
+            values,
+            None,
+            target,
+            check_num_values,
+            verify_utf8,
+        ),
+        (Some(Filter::Range(rng)), None) if rng.start == 0 => required::decode(
+            max_num_values,
+            values,
+            Some(rng.end),
+            target,
+            false,
+            verify_utf8,
+        ),
         (None, Some(page_validity)) => optional::decode(
             page_validity.set_bits(),
             values,
             target,
             &page_validity,
+            check_num_values,
             verify_utf8,
         ),
         (Some(Filter::Range(rng)), Some(page_validity)) if rng.start == 0 => optional::decode(
@@ -265,6 +543,7 @@ fn decode_plain(
             values,
             target,
             &page_validity,
+            false,
             verify_utf8,
         ),
         (Some(Filter::Mask(mask)), None) => {
@@ -299,25 +578,247 @@ fn decode_plain(
     Ok(())
 }
 
+/// Decode a plain page like [`decode_plain`], keeping only the non-null values for which
+/// `is_match` returns `true`.
+///
+/// `max_num_values` is the number of rows in the page. Returns a mask over those rows that is set
+/// for the rows that were kept; null rows are always dropped. The page is decoded in a single pass
+/// that calls `is_match` once for every non-null value.
+#[allow(clippy::too_many_arguments)]
+pub fn decode_plain_with_callback(
+    values: &[u8],
+    max_num_values: usize,
+    target: &mut MutableBinaryViewArray<[u8]>,
+
+    is_optional: bool,
+    validity: &mut BitmapBuilder,
+
+    page_validity: Option<&Bitmap>,
+    is_match: impl Fn(&[u8]) -> bool,
+    verify_utf8: bool,
+) -> ParquetResult<Bitmap> {
+    let num_valid_values = page_validity.map_or(max_num_values, |v| v.set_bits());
+    let mut pred_true_mask = BitmapBuilder::with_capacity(num_valid_values);
+
+    // Null rows are dropped and the valid rows are only kept if they match.
+    let start_length = target.len();
+    let mut validity_iter = page_validity.map(|v| v.iter());
+    let mut num_rows_remaining = max_num_values;
+    decode_plain_generic_with_callback(
+        values,
+        target,
+        max_num_values,
+        || {
+            if num_rows_remaining == 0 {
+                return None;
+            }
+            num_rows_remaining -= 1;
+
+            let is_valid = match &mut validity_iter {
+                None => true,
+                Some(validity_iter) => validity_iter.next()?,
+            };
+            Some((is_valid, is_valid))
+        },
+        |value| {
+            let is_kept = is_match(value);
+            pred_true_mask.push(is_kept);
+            is_kept
+        },
+        verify_utf8,
+    )?;
+    let pred_true_mask = pred_true_mask.freeze();
+
+    if is_optional {
+        validity.extend_constant(target.len() - start_length, true);
+    }
+
+    // Spread the matches over the valid rows of the page.
+    let mask = match page_validity {
+        None => pred_true_mask,
+        Some(page_validity) => {
+            let mut matches = pred_true_mask.iter();
+            let mut mask = BitmapBuilder::with_capacity(max_num_values);
+            for is_valid in page_validity.iter() {
+                mask.push(is_valid && matches.next().unwrap());
+            }
+            mask.freeze()
+        },
+    };
+
+    Ok(mask)
+}
+
+/// Count the lengths of the `num_values` plain encoded values in `values` in power-of-two buckets,
+/// without copying the values.
+///
+/// Bucket `0` counts the empty values and bucket `i > 0` counts the values with a length in
+/// `2^(i-1)..2^i`. Summing the histograms of all pages gives the histogram of a column.
+pub fn plain_length_histogram(
+    mut values: &[u8],
+    num_values: usize,
+) -> ParquetResult<[usize; u32::BITS as usize + 1]> {
+    let mut histogram = [0; u32::BITS as usize + 1];
+    for _ in 0..num_values {
+        if values.len() < 4 {
+            return Err(invalid_input_err());
+        }
+
+        let length;
+        (length, values) = values.split_at(4);
+        let length: &[u8; 4] = unsafe { length.try_into().unwrap_unchecked() };
+        let length = u32::from_le_bytes(*length);
+
+        if values.len() < length as usize {
+            return Err(invalid_input_err());
+        }
+        values = &values[length as usize..];
+
+        histogram[(u32::BITS - length.leading_zeros()) as usize] += 1;
+    }
+
+    Ok(histogram)
+}
+
+/// Check that none of the plain encoded values in `values` is longer than `max_value_len`, without
+/// copying them.
+fn verify_plain_value_lens(mut values: &[u8], max_value_len: usize) -> ParquetResult<()> {
+    let mut idx = 0;
+    while values.len() >= 4 {
+        let length;
+        (length, values) = values.split_at(4);
+        let length: &[u8; 4] = unsafe { length.try_into().unwrap_unchecked() };
+        let length = u32::from_le_bytes(*length) as usize;
+
+        if length > max_value_len {
+            return Err(value_too_long_err(idx, length, max_value_len));
+        }
+        values = values.get(length..).ok_or_else(invalid_input_err)?;
+        idx += 1;
+    }
+
+    Ok(())
+}
+
+#[cold]
+fn value_too_long_err(idx: usize, length: usize, max_value_len: usize) -> ParquetError {
+    ParquetError::oos(format!(
+        "Value {idx} of the page is {length} bytes long, which is more than the maximum value \
+        length of {max_value_len}"
+    ))
+}
+
+#[cold]
+fn null_value_err(row_idx: usize) -> ParquetError {
+    ParquetError::oos(format!("Found a null in row {row_idx} of the page"))
+}
+
 #[cold]
 fn invalid_input_err() -> ParquetError {
     ParquetError::oos("String data does not match given length")
 }
 
+#[cold]
+fn too_many_values_err(num_expected_values: usize) -> ParquetError {
+    ParquetError::oos(format!(
+        "Page contains more than the {num_expected_values} non-null values declared by its header"
+    ))
+}
+
 #[cold]
 fn invalid_utf8_err() -> ParquetError {
     ParquetError::oos("String data contained invalid UTF-8")
 }
 
+/// Create an error that points to the first value that is not valid UTF-8.
+///
+/// The index is into the values decoded from the current page, so filtered out rows and nulls
+/// are not counted. If no single value is invalid, e.g. the invalid value was filtered out, this
+/// falls back to [`invalid_utf8_err`].
+#[cold]
+fn locate_invalid_utf8_err<'a>(values: impl IntoIterator<Item = &'a [u8]>) -> ParquetError {
+    for (i, value) in values.into_iter().enumerate() {
+        if let Err(err) = simdutf8::compat::from_utf8(value) {
+            return invalid_utf8_at_err(i, err);
+        }
+    }
+
+    invalid_utf8_err()
+}
+
+#[cold]
+fn invalid_utf8_at_err(idx: usize, err: simdutf8::compat::Utf8Error) -> ParquetError {
+    ParquetError::oos(format!(
+        "String data contained invalid UTF-8 in decoded value {idx} of the page \
+        (at byte offset {} of the value)",
+        err.valid_up_to()
+    ))
+}
+
+/// Verify that the lengths of a `DeltaLengthByteArray` page fit into the values of that page.
+///
+/// Corrupt files could otherwise cause out-of-bounds slicing when splitting up the values.
+fn verify_delta_lengths(lengths: &[i64], num_bytes: usize) -> ParquetResult<()> {
+    let mut remaining = num_bytes;
+    for (i, &length) in lengths.iter().enumerate() {
+        let Ok(length) = usize::try_from(length) else {
+            return Err(ParquetError::oos(format!(
+                "DeltaLengthByteArray page contains negative length {length} for value {i}"
+            )));
+        };
+
+        if length > remaining {
+            return Err(ParquetError::oos(format!(
+                "DeltaLengthByteArray page length of value {i} exceeds the remaining page data \
+                ({remaining} bytes remaining, {length} bytes requested)"
+            )));
+        }
+        remaining -= length;
+    }
+
+    Ok(())
+}
+
+/// Get the bytes of the `views` pushed during decoding of a single page, where all non-inlined
+/// views point into `buffer`.
+fn page_view_values<'a>(views: &'a [View], buffer: &'a [u8]) -> impl Iterator<Item = &'a [u8]> {
+    views.iter().map(move |view| {
+        if view.length <= View::MAX_INLINE_SIZE {
+            // SAFETY: We just checked that the view is inlined.
+            unsafe { view.get_inlined_slice_unchecked() }
+        } else {
+            &buffer[view.offset as usize..][..view.length as usize]
+        }
+    })
+}
+
+/// Decode plain encoded values from `values` into `target`, returning the number of bytes of
+/// `values` that were not consumed.
+#[inline]
 pub fn decode_plain_generic(
     values: &[u8],
     target: &mut MutableBinaryViewArray<[u8]>,
 
+    num_rows: usize,
+    next: impl FnMut() -> Option<(bool, bool)>,
+
+    verify_utf8: bool,
+) -> ParquetResult<usize> {
+    decode_plain_generic_with_callback(values, target, num_rows, next, |_| true, verify_utf8)
+}
+
+/// Decode plain encoded values like [`decode_plain_generic`], only storing the selected values for
+/// which `keep_value` returns `true`. `keep_value` is called once for every selected valid value.
+fn decode_plain_generic_with_callback(
+    values: &[u8],
+    target: &mut MutableBinaryViewArray<[u8]>,
+
     num_rows: usize,
     mut next: impl FnMut() -> Option<(bool, bool)>,
+    mut keep_value: impl FnMut(&[u8]) -> bool,
 
     verify_utf8: bool,
-) -> ParquetResult<()> {
+) -> ParquetResult<usize> {
     // Since the offset in the buffer is decided by the interleaved lengths, every value has to be
     // walked no matter what. This makes decoding rather inefficient in general.
     //
@@ -380,7 +881,7 @@ pub fn decode_plain_generic(
         none_starting_with_continuation_byte &=
             value.is_empty() || value[0] & 0b1100_0000 != 0b1000_0000;
 
-        if !is_selected {
+        if !is_selected || !keep_value(value) {
             continue;
         }
 
@@ -408,10 +909,13 @@ pub fn decode_plain_generic(
         //
         // This is allowed if none of the strings start with a UTF-8 continuation byte, so we keep
         // track of that during the decoding.
+        let page_views = &target.views()[start_target_length..];
         if num_inlined == 0 {
             if !none_starting_with_continuation_byte || simdutf8::basic::from_utf8(&buffer).is_err()
             {
-                return Err(invalid_utf8_err());
+                return Err(locate_invalid_utf8_err(page_view_values(
+                    page_views, &buffer,
+                )));
             }
 
         // This is a small trick that allows us to check the Parquet buffer instead of the view
@@ -423,20 +927,24 @@ pub fn decode_plain_generic(
         // Consequently, it is valid to just check the whole buffer.
         } else if all_len_below_128 {
             if simdutf8::basic::from_utf8(&values[..values.len() - mvalues.len()]).is_err() {
-                return Err(invalid_utf8_err());
+                return Err(locate_invalid_utf8_err(page_view_values(
+                    page_views, &buffer,
+                )));
             }
         } else {
             // We check all the non-inlined values here.
             if !none_starting_with_continuation_byte || simdutf8::basic::from_utf8(&buffer).is_err()
             {
-                return Err(invalid_utf8_err());
+                return Err(locate_invalid_utf8_err(page_view_values(
+                    page_views, &buffer,
+                )));
             }
 
             let mut all_inlined_are_ascii = true;
 
             // @NOTE: This is only valid because we initialize our inline View's to be zeroes on
             // non-included bytes.
-            for view in &target.views()[start_target_length..] {
+            for view in page_views {
                 all_inlined_are_ascii &= (view.length > View::MAX_INLINE_SIZE)
                     | (view.as_u128() & 0x0000_0000_8080_8080_8080_8080_8080_8080 == 0);
             }
@@ -444,7 +952,7 @@ pub fn decode_plain_generic(
             // This is the very slow path.
             if !all_inlined_are_ascii {
                 let mut is_valid = true;
-                for view in &target.views()[start_target_length..] {
+                for view in page_views {
                     if view.length <= View::MAX_INLINE_SIZE {
                         is_valid &=
                             std::str::from_utf8(unsafe { view.get_inlined_slice_unchecked() })
@@ -453,7 +961,9 @@ pub fn decode_plain_generic(
                 }
 
                 if !is_valid {
-                    return Err(invalid_utf8_err());
+                    return Err(locate_invalid_utf8_err(page_view_values(
+                        page_views, &buffer,
+                    )));
                 }
             }
         }
@@ -461,7 +971,7 @@ pub fn decode_plain_generic(
 
     target.push_buffer(buffer.into());
 
-    Ok(())
+    Ok(mvalues.len())
 }
 
 impl utils::Decoder for BinViewDecoder {
@@ -600,8 +1110,21 @@ impl utils::Decoder for BinViewDecoder {
         let values = &page.buffer;
         let num_values = page.num_values;
 
+        if let Some(max_value_len) = self.options.max_value_len {
+            verify_plain_value_lens(values, max_value_len)
+                .map_err(|err| self.with_column_context(err))?;
+        }
+
         let mut arr = MutableBinaryViewArray::new();
-        required::decode(num_values, values, None, &mut arr, self.is_string)?;
+        required::decode(
+            num_values,
+            values,
+            None,
+            &mut arr,
+            false,
+            self.verify_utf8(),
+        )
+        .map_err(|err| self.with_column_context(err))?;
 
         Ok(arr.freeze())
     }
@@ -654,115 +1177,21 @@ impl utils::Decoder for BinViewDecoder {
         filter: Option<super::Filter>,
         _chunks: &mut Vec<Self::Output>,
     ) -> ParquetResult<()> {
-        let is_string = self.is_string;
-        let equals_one_of_state = self.initialize_decode_equals_one_of_state(&mut decoded.0);
-        match state.translation {
-            StateTranslation::Plain(iter) => decode_plain(
-                iter.values,
-                iter.max_num_values,
-                &mut decoded.0,
-                state.is_optional,
-                &mut decoded.1,
-                state.page_validity.as_ref(),
-                filter,
-                equals_one_of_state,
-                is_string,
-            ),
-            StateTranslation::Dictionary(ref mut indexes) => {
-                let dict = state.dict.unwrap();
-
-                let start_length = decoded.0.views().len();
-
-                dictionary_encoded::decode_dict(
-                    indexes.clone(),
-                    dict.views().as_slice(),
-                    state.is_optional,
-                    state.page_validity.as_ref(),
-                    filter,
-                    &mut decoded.1,
-                    unsafe { decoded.0.views_mut() },
-                )?;
-
-                let total_length: usize = decoded
-                    .0
-                    .views()
-                    .iter()
-                    .skip(start_length)
-                    .map(|view| view.length as usize)
-                    .sum();
-                unsafe {
-                    decoded
-                        .0
-                        .set_total_bytes_len(decoded.0.total_bytes_len() + total_length);
-                }
-
-                Ok(())
-            },
-            StateTranslation::DeltaLengthByteArray(decoder, _vec) => {
-                let values = decoder.values;
-                let lengths = decoder.lengths.collect::<Vec<i64>>()?;
-
-                if self.is_string {
-                    let mut none_starting_with_continuation_byte = true;
-                    let mut offset = 0;
-                    for length in &lengths {
-                        none_starting_with_continuation_byte &=
-                            *length == 0 || values[offset] & 0xC0 != 0x80;
-                        offset += *length as usize;
-                    }
-
-                    if !none_starting_with_continuation_byte {
-                        return Err(invalid_utf8_err());
-                    }
-
-                    if simdutf8::basic::from_utf8(&values[..offset]).is_err() {
-                        return Err(invalid_utf8_err());
-                    }
-                }
-
-                let mut i = 0;
-                let mut offset = 0;
-                unspecialized_decode(
-                    lengths.len(),
-                    || {
-                        let length = lengths[i] as usize;
-
-                        let value = &values[offset..offset + length];
-
-                        i += 1;
-                        offset += length;
-
-                        Ok(value)
-                    },
-                    filter,
-                    state.page_validity,
-                    state.is_optional,
-                    &mut decoded.1,
-                    &mut decoded.0,
-                )
-            },
-            StateTranslation::DeltaBytes(mut decoder) => {
-                let check_utf8 = self.is_string;
-
-                unspecialized_decode(
-                    decoder.len(),
-                    || {
-                        let value = decoder.next().unwrap()?;
-
-                        if check_utf8 && simdutf8::basic::from_utf8(&value[..]).is_err() {
-                            return Err(invalid_utf8_err());
-                        }
-
-                        Ok(value)
-                    },
-                    filter,
-                    state.page_validity,
-                    state.is_optional,
-                    &mut decoded.1,
-                    &mut decoded.0,
-                )
-            },
+        let encoding = state.translation.encoding();
+        let start_len = decoded.0.len();
+        let start_bytes_len = decoded.0.total_bytes_len();
+
+        self.decode_state(state, decoded, filter)
+            .map_err(|err| self.with_column_context(err))?;
+
+        if let Some(progress) = &self.options.progress {
+            progress.record_page(
+                encoding,
+                decoded.0.len() - start_len,
+                decoded.0.total_bytes_len() - start_bytes_len,
+            );
         }
+        Ok(())
     }
 
     fn extend_constant(
@@ -864,3 +1293,446 @@ impl<'a> Iterator for BinaryIter<'a> {
         (0, Some(self.max_num_values))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parquet::CowBuffer;
+    use crate::read::deserialize::utils::Decoder;
+
+    #[test]
+    fn test_locate_invalid_utf8() {
+        let values: [&[u8]; 3] = [b"abc", b"de\xFFf", b"\xFF"];
+        let err = locate_invalid_utf8_err(values).to_string();
+        assert!(err.contains("decoded value 1 of the page"));
+        assert!(err.contains("byte offset 2 of the value"));
+
+        let values: [&[u8]; 2] = [b"abc", b"def"];
+        let err = locate_invalid_utf8_err(values).to_string();
+        assert!(err.ends_with("String data contained invalid UTF-8"));
+    }
+
+    #[test]
+    fn test_truncated_delta_length_page() -> ParquetResult<()> {
+        let data = ["aa", "bbb", "a", "aa", "b"];
+
+        let mut buffer = vec![];
+        delta_length_byte_array::encode(data.iter().map(|x| x.as_bytes()), &mut buffer);
+
+        let decoder = delta_length_byte_array::Decoder::try_new(&buffer)?;
+        let values = decoder.values;
+        let lengths = decoder.lengths.collect::<Vec<i64>>()?;
+        verify_delta_lengths(&lengths, values.len())?;
+
+        // Drop the last two value bytes so the final length points past the end of the page.
+        let truncated = &buffer[..buffer.len() - 2];
+        let decoder = delta_length_byte_array::Decoder::try_new(truncated)?;
+        let values = decoder.values;
+        let lengths = decoder.lengths.collect::<Vec<i64>>()?;
+        let err = verify_delta_lengths(&lengths, values.len()).unwrap_err();
+        assert!(err.to_string().contains("length of value 3"));
+
+        assert!(verify_delta_lengths(&[1, -1], 8).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_utf8_dict_page() {
+        // Plain encoded values are prefixed by their length as a little-endian u32.
+        let mut buffer = vec![];
+        for value in [&b"valid"[..], &b"inv\xC3lid"[..]] {
+            buffer.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            buffer.extend_from_slice(value);
+        }
+
+        let page = DictPage::new(CowBuffer::Owned(buffer.clone()), 2, false);
+        assert!(BinViewDecoder::new_string().deserialize_dict(page).is_err());
+
+        let page = DictPage::new(CowBuffer::Owned(buffer), 2, false);
+        let dict = BinViewDecoder::new(false).deserialize_dict(page).unwrap();
+        assert_eq!(dict.value(1), b"inv\xC3lid");
+    }
+
+    #[test]
+    fn test_plain_page_with_underreported_num_values() {
+        let mut buffer = vec![];
+        for value in [&b"a"[..], &b"bb"[..], &b"ccc"[..]] {
+            buffer.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            buffer.extend_from_slice(value);
+        }
+
+        let mut target = MutableBinaryViewArray::new();
+        required::decode(3, &buffer, None, &mut target, true, true).unwrap();
+        assert_eq!(target.len(), 3);
+
+        let mut target = MutableBinaryViewArray::new();
+        let err = required::decode(2, &buffer, None, &mut target, true, true).unwrap_err();
+        assert!(err.to_string().contains("more than the 2 non-null values"));
+
+        // Without the check, the trailing value is ignored.
+        let mut target = MutableBinaryViewArray::new();
+        required::decode(2, &buffer, None, &mut target, false, true).unwrap();
+        assert_eq!(target.len(), 2);
+
+        let page_validity = Bitmap::from([true, false, true, true]);
+        let mut target = MutableBinaryViewArray::new();
+        optional::decode(3, &buffer, &mut target, &page_validity, true, true).unwrap();
+        assert_eq!(target.len(), 4);
+
+        let page_validity = Bitmap::from([true, false, true]);
+        let mut target = MutableBinaryViewArray::new();
+        let err =
+            optional::decode(2, &buffer, &mut target, &page_validity, true, true).unwrap_err();
+        assert!(err.to_string().contains("more than the 2 non-null values"));
+
+        let mut target = MutableBinaryViewArray::new();
+        optional::decode(2, &buffer, &mut target, &page_validity, false, true).unwrap();
+        assert_eq!(target.len(), 3);
+
+        // Dictionary pages are not checked.
+        let page = DictPage::new(CowBuffer::Owned(buffer), 2, false);
+        let dict = BinViewDecoder::new(false).deserialize_dict(page).unwrap();
+        assert_eq!(dict.len(), 2);
+    }
+
+    #[test]
+    fn test_decode_plain_with_callback() -> ParquetResult<()> {
+        let data = [&b"a"[..], &b"bbb"[..], &b"cc"[..], &b"dddd"[..]];
+        let mut buffer = vec![];
+        for value in data {
+            buffer.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            buffer.extend_from_slice(value);
+        }
+        let page_validity = Bitmap::from([true, false, true, true, false, true]);
+        let is_match = |v: &[u8]| v.len() >= 2;
+
+        // Decode the whole page and filter afterwards.
+        let mut target = MutableBinaryViewArray::<[u8]>::new();
+        let mut validity = BitmapBuilder::new();
+        decode_plain(
+            &buffer,
+            page_validity.len(),
+            &mut target,
+            true,
+            &mut validity,
+            Some(&page_validity),
+            None,
+            None,
+            true,
+            true,
+        )?;
+        let array: BinaryViewArray = target.freeze();
+        let expected = array
+            .values_iter()
+            .zip(validity.freeze().iter())
+            .filter(|(v, is_valid)| *is_valid && is_match(v))
+            .map(|(v, _)| v.to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            expected,
+            [b"bbb".to_vec(), b"cc".to_vec(), b"dddd".to_vec()]
+        );
+
+        let mut target = MutableBinaryViewArray::<[u8]>::new();
+        let mut validity = BitmapBuilder::new();
+        let mask = decode_plain_with_callback(
+            &buffer,
+            page_validity.len(),
+            &mut target,
+            true,
+            &mut validity,
+            Some(&page_validity),
+            is_match,
+            true,
+        )?;
+        assert_eq!(
+            mask.iter().collect::<Vec<_>>(),
+            [false, false, true, true, false, true]
+        );
+        let array: BinaryViewArray = target.freeze();
+        let validity = validity.freeze();
+        assert_eq!(validity.len(), 3);
+        assert_eq!(validity.unset_bits(), 0);
+        let out = array.values_iter().map(|v| v.to_vec()).collect::<Vec<_>>();
+        assert_eq!(out, expected);
+
+        // Without a page validity every row has a value.
+        let mut target = MutableBinaryViewArray::<[u8]>::new();
+        let mut validity = BitmapBuilder::new();
+        let mask = decode_plain_with_callback(
+            &buffer,
+            data.len(),
+            &mut target,
+            false,
+            &mut validity,
+            None,
+            is_match,
+            true,
+        )?;
+        assert_eq!(mask.iter().collect::<Vec<_>>(), [false, true, true, true]);
+        assert_eq!(validity.len(), 0);
+        let array: BinaryViewArray = target.freeze();
+        let out = array.values_iter().map(|v| v.to_vec()).collect::<Vec<_>>();
+        assert_eq!(out, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_plain_length_histogram() -> ParquetResult<()> {
+        let data = ["", "a", "bb", "ccc", "dddd", "eeeeeeeeeeeeeeeeeeeee"];
+        let mut buffer = vec![];
+        for value in data {
+            buffer.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            buffer.extend_from_slice(value.as_bytes());
+        }
+
+        let histogram = plain_length_histogram(&buffer, data.len())?;
+        assert_eq!(histogram.iter().sum::<usize>(), data.len());
+        assert_eq!(histogram[..6], [1, 1, 2, 1, 0, 1]);
+
+        assert!(plain_length_histogram(&buffer[..buffer.len() - 1], data.len()).is_err());
+
+        Ok(())
+    }
+
+    fn byte_array_page(encoding: Encoding, page_validity: &[bool], data: &[&[u8]]) -> DataPage {
+        use crate::parquet::metadata::Descriptor;
+        use crate::parquet::page::{DataPageHeader, DataPageHeaderV1};
+        use crate::parquet::schema::types::PhysicalType;
+
+        let mut def_levels = vec![];
+        hybrid_rle::encode::<bool, _, _>(&mut def_levels, page_validity.iter().copied(), 1)
+            .unwrap();
+        let mut buffer = (def_levels.len() as i32).to_le_bytes().to_vec();
+        buffer.extend_from_slice(&def_levels);
+        match encoding {
+            Encoding::Plain => {
+                for value in data {
+                    buffer.extend_from_slice(&(value.len() as u32).to_le_bytes());
+                    buffer.extend_from_slice(value);
+                }
+            },
+            Encoding::DeltaLengthByteArray => {
+                delta_length_byte_array::encode(data.iter().copied(), &mut buffer)
+            },
+            Encoding::DeltaByteArray => delta_byte_array::encode(data.iter().copied(), &mut buffer),
+            _ => unreachable!(),
+        }
+
+        DataPage::new(
+            DataPageHeader::V1(DataPageHeaderV1 {
+                num_values: page_validity.len() as i32,
+                encoding: encoding.into(),
+                definition_level_encoding: Encoding::Rle.into(),
+                repetition_level_encoding: Encoding::Rle.into(),
+                statistics: None,
+            }),
+            CowBuffer::Owned(buffer),
+            Descriptor {
+                primitive_type: PrimitiveType::from_physical("a".into(), PhysicalType::ByteArray),
+                max_def_level: 1,
+                max_rep_level: 0,
+            },
+            page_validity.len(),
+        )
+    }
+
+    #[test]
+    fn test_max_value_len() -> ParquetResult<()> {
+        let options = BinaryDecodeOptions {
+            max_value_len: Some(3),
+            ..Default::default()
+        };
+        let page_validity = [true, true, false, true];
+        let data: [&[u8]; 3] = [b"abc", b"de", b"fghij"];
+
+        for encoding in [
+            Encoding::Plain,
+            Encoding::DeltaLengthByteArray,
+            Encoding::DeltaByteArray,
+        ] {
+            let page = byte_array_page(encoding, &page_validity, &data);
+            let mut decoder = BinViewDecoder::new(false).with_options(&options);
+            let state = utils::State::new(&decoder, &page, None)?;
+            let mut decoded = decoder.with_capacity(4);
+            let err = decoder
+                .extend_filtered_with_state(state, &mut decoded, None, &mut vec![])
+                .unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("Value 2 of the page is 5 bytes long"),
+                "{encoding:?}: {err}"
+            );
+
+            let mut decoder = BinViewDecoder::new(false);
+            let state = utils::State::new(&decoder, &page, None)?;
+            let mut decoded = decoder.with_capacity(4);
+            decoder.extend_filtered_with_state(state, &mut decoded, None, &mut vec![])?;
+            assert_eq!(decoded.0.len(), 4);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_on_null() -> ParquetResult<()> {
+        let options = BinaryDecodeOptions {
+            error_on_null: true,
+            ..Default::default()
+        };
+        let page = byte_array_page(
+            Encoding::Plain,
+            &[true, true, true, false, true],
+            &[b"a", b"b", b"c", b"d"],
+        );
+
+        let mut decoder = BinViewDecoder::new(false)
+            .with_column(&page.descriptor.primitive_type)
+            .with_options(&options);
+        let state = utils::State::new(&decoder, &page, None)?;
+        let mut decoded = decoder.with_capacity(5);
+        let err = decoder
+            .extend_filtered_with_state(state, &mut decoded, None, &mut vec![])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Found a null in row 3 of the page (column 'a'"));
+        assert_eq!(decoded.0.len(), 0);
+
+        let page = byte_array_page(Encoding::Plain, &[true, true], &[b"a", b"b"]);
+        let state = utils::State::new(&decoder, &page, None)?;
+        decoder.extend_filtered_with_state(state, &mut decoded, None, &mut vec![])?;
+        assert_eq!(decoded.0.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_progress() -> ParquetResult<()> {
+        let progress = Arc::new(BinaryDecodeProgress::default());
+        let options = BinaryDecodeOptions {
+            progress: Some(progress.clone()),
+            ..Default::default()
+        };
+        let pages = [
+            byte_array_page(Encoding::Plain, &[true, false, true], &[b"abc", b"de"]),
+            byte_array_page(Encoding::DeltaLengthByteArray, &[false, false], &[]),
+            byte_array_page(Encoding::DeltaByteArray, &[true, true], &[b"fgh", b"fghij"]),
+        ];
+
+        let mut decoder = BinViewDecoder::new(false).with_options(&options);
+        let mut decoded = decoder.with_capacity(7);
+        let mut seen = vec![(progress.rows_decoded(), progress.bytes_decoded())];
+        let mut encodings = vec![progress.last_encoding()];
+        for page in &pages {
+            let state = utils::State::new(&decoder, page, None)?;
+            decoder.extend_filtered_with_state(state, &mut decoded, None, &mut vec![])?;
+            seen.push((progress.rows_decoded(), progress.bytes_decoded()));
+            encodings.push(progress.last_encoding());
+        }
+        assert_eq!(seen, [(0, 0), (3, 5), (5, 5), (7, 13)]);
+        assert_eq!(
+            encodings,
+            [
+                None,
+                Some(Encoding::Plain),
+                Some(Encoding::DeltaLengthByteArray),
+                Some(Encoding::DeltaByteArray),
+            ]
+        );
+        assert_eq!(decoded.0.total_bytes_len(), 13);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_skip_utf8_verification() -> ParquetResult<()> {
+        let options = BinaryDecodeOptions {
+            verify_utf8: false,
+            ..Default::default()
+        };
+
+        let page = byte_array_page(
+            Encoding::DeltaLengthByteArray,
+            &[true, false, true],
+            &[b"abc", b"de\xFFf"],
+        );
+        let mut decoder = BinViewDecoder::new_string().with_options(&options);
+        let state = utils::State::new(&decoder, &page, None)?;
+        let mut decoded = decoder.with_capacity(3);
+        decoder.extend_filtered_with_state(state, &mut decoded, None, &mut vec![])?;
+        assert_eq!(decoded.0.len(), 3);
+
+        // The dictionary page is not verified either.
+        let mut buffer = vec![];
+        for value in [&b"a"[..], &b"inv\xC3lid"[..]] {
+            buffer.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            buffer.extend_from_slice(value);
+        }
+        let page = DictPage::new(CowBuffer::Owned(buffer), 2, false);
+        let dict = decoder.deserialize_dict(page)?;
+        assert_eq!(dict.value(1), b"inv\xC3lid");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_utf8_names_column() -> ParquetResult<()> {
+        let page = byte_array_page(
+            Encoding::DeltaLengthByteArray,
+            &[true, false, true],
+            &[b"abc", b"de\xFFf"],
+        );
+
+        let mut decoder = BinViewDecoder::new_string().with_column(&page.descriptor.primitive_type);
+        let state = utils::State::new(&decoder, &page, None)?;
+        let mut decoded = decoder.with_capacity(3);
+        let err = decoder
+            .extend_filtered_with_state(state, &mut decoded, None, &mut vec![])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("decoded value 1 of the page"));
+        assert!(err.contains("column 'a', physical type ByteArray"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_skip_delta_length_with_validity() -> ParquetResult<()> {
+        // A null-heavy prefix of rows that is skipped.
+        let page_validity = [false, false, false, true, false, true, true, false];
+        let page = byte_array_page(
+            Encoding::DeltaLengthByteArray,
+            &page_validity,
+            &[b"aa", b"bbb", b"c"],
+        );
+
+        let decode = |filter: Option<Filter>| {
+            let mut decoder = BinViewDecoder::new_string();
+            let state = utils::State::new(&decoder, &page, None)?;
+            let mut decoded = decoder.with_capacity(page_validity.len());
+            decoder.extend_filtered_with_state(state, &mut decoded, filter, &mut vec![])?;
+
+            let (target, validity) = decoded;
+            let array: BinaryViewArray = target.freeze();
+            let validity = validity.freeze();
+            ParquetResult::Ok(
+                array
+                    .values_iter()
+                    .zip(validity.iter())
+                    .map(|(v, is_valid)| is_valid.then(|| v.to_vec()))
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        let expected = [None, Some(b"bbb".to_vec()), Some(b"c".to_vec()), None];
+        assert_eq!(decode(None)?[4..], expected);
+        assert_eq!(decode(Some(Filter::Range(4..8)))?, expected);
+
+        let mask = Bitmap::from([false, false, false, false, true, true, true, true]);
+        assert_eq!(decode(Some(Filter::Mask(mask)))?, expected);
+
+        Ok(())
+    }
+}
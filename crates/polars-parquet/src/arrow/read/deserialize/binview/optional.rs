@@ -1,15 +1,20 @@
 use arrow::array::MutableBinaryViewArray;
 use arrow::bitmap::Bitmap;
 
-use super::decode_plain_generic;
+use super::{decode_plain_generic, too_many_values_err};
 use crate::parquet::error::ParquetResult;
 
+/// Decode the values of the rows in `page_validity`.
+///
+/// `check_num_values` should only be set if the whole page is decoded. All bytes of the page are
+/// then expected to be consumed.
 pub fn decode(
     num_expected_values: usize,
     values: &[u8],
     target: &mut MutableBinaryViewArray<[u8]>,
     page_validity: &Bitmap,
 
+    check_num_values: bool,
     verify_utf8: bool,
 ) -> ParquetResult<()> {
     if page_validity.unset_bits() == 0 {
@@ -18,16 +23,23 @@ pub fn decode(
             values,
             Some(page_validity.len()),
             target,
+            check_num_values,
             verify_utf8,
         );
     }
 
     let mut validity_iter = page_validity.iter();
-    decode_plain_generic(
+    let num_unconsumed_bytes = decode_plain_generic(
         values,
         target,
         page_validity.len(),
         || Some((validity_iter.next()?, true)),
         verify_utf8,
-    )
+    )?;
+
+    if check_num_values && num_unconsumed_bytes > 0 {
+        return Err(too_many_values_err(num_expected_values));
+    }
+
+    Ok(())
 }
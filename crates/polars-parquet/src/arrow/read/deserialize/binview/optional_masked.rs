@@ -22,6 +22,7 @@ pub fn decode(
             values,
             target,
             page_validity,
+            false,
             verify_utf8,
         );
     }
@@ -43,5 +44,7 @@ pub fn decode(
         mask.set_bits(),
         || Some((validity_iter.next()?, mask_iter.next()?)),
         verify_utf8,
-    )
+    )?;
+
+    Ok(())
 }
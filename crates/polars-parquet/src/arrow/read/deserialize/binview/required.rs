@@ -1,20 +1,25 @@
 use arrow::array::MutableBinaryViewArray;
 
-use super::decode_plain_generic;
+use super::{decode_plain_generic, too_many_values_err};
 use crate::parquet::error::ParquetResult;
 
+/// Decode `limit` values, or all `num_expected_values` if no limit is given.
+///
+/// `check_num_values` should only be set if the whole page is decoded. All bytes of the page are
+/// then expected to be consumed.
 pub fn decode(
     num_expected_values: usize,
     values: &[u8],
     limit: Option<usize>,
     target: &mut MutableBinaryViewArray<[u8]>,
 
+    check_num_values: bool,
     verify_utf8: bool,
 ) -> ParquetResult<()> {
     let limit = limit.unwrap_or(num_expected_values);
 
     let mut idx = 0;
-    decode_plain_generic(
+    let num_unconsumed_bytes = decode_plain_generic(
         values,
         target,
         limit,
@@ -28,5 +33,11 @@ pub fn decode(
             Some((true, true))
         },
         verify_utf8,
-    )
+    )?;
+
+    if check_num_values && num_unconsumed_bytes > 0 {
+        return Err(too_many_values_err(num_expected_values));
+    }
+
+    Ok(())
 }
@@ -19,6 +19,7 @@ pub fn decode(
             values,
             Some(mask.len()),
             target,
+            false,
             verify_utf8,
         );
     }
@@ -30,5 +31,7 @@ pub fn decode(
         mask.set_bits(),
         || Some((true, mask_iter.next()?)),
         verify_utf8,
-    )
+    )?;
+
+    Ok(())
 }
@@ -20,6 +20,9 @@ use arrow::offset::Offsets;
 use polars_utils::mmap::MemReader;
 use simple::page_iter_to_array;
 
+pub use self::binview::{
+    BinaryDecodeOptions, BinaryDecodeProgress, decode_plain_with_callback, plain_length_histogram,
+};
 pub use self::nested_utils::{InitNested, NestedState, init_nested};
 pub use self::utils::filter::{Filter, PredicateFilter};
 use self::utils::freeze_validity;
@@ -135,6 +138,7 @@ fn columns_to_iter_recursive(
     field: Field,
     init: Vec<InitNested>,
     filter: Option<Filter>,
+    binary_options: &BinaryDecodeOptions,
 ) -> ParquetResult<(NestedState, Vec<Box<dyn Array>>, Bitmap)> {
     if init.is_empty() && is_primitive(&field.dtype) {
         let (_, array, pred_true_mask) = page_iter_to_array(
@@ -143,12 +147,13 @@ fn columns_to_iter_recursive(
             field,
             filter,
             None,
+            binary_options,
         )?;
 
         return Ok((NestedState::default(), array, pred_true_mask));
     }
 
-    nested::columns_to_iter_recursive(columns, types, field, init, filter)
+    nested::columns_to_iter_recursive(columns, types, field, init, filter, binary_options)
 }
 
 /// Returns the number of (parquet) columns that a [`ArrowDataType`] contains.
@@ -199,8 +204,25 @@ pub fn column_iter_to_arrays(
     types: Vec<&PrimitiveType>,
     field: Field,
     filter: Option<Filter>,
+) -> PolarsResult<(Vec<Box<dyn Array>>, Bitmap)> {
+    column_iter_to_arrays_with_options(
+        columns,
+        types,
+        field,
+        filter,
+        &BinaryDecodeOptions::default(),
+    )
+}
+
+/// Like [`column_iter_to_arrays`], decoding binary and string columns with `binary_options`.
+pub fn column_iter_to_arrays_with_options(
+    columns: Vec<BasicDecompressor>,
+    types: Vec<&PrimitiveType>,
+    field: Field,
+    filter: Option<Filter>,
+    binary_options: &BinaryDecodeOptions,
 ) -> PolarsResult<(Vec<Box<dyn Array>>, Bitmap)> {
     let (_, array, pred_true_mask) =
-        columns_to_iter_recursive(columns, types, field, vec![], filter)?;
+        columns_to_iter_recursive(columns, types, field, vec![], filter, binary_options)?;
     Ok((array, pred_true_mask))
 }
@@ -18,12 +18,14 @@ pub fn columns_to_iter_recursive(
     field: Field,
     mut init: Vec<InitNested>,
     filter: Option<Filter>,
+    binary_options: &BinaryDecodeOptions,
 ) -> ParquetResult<(NestedState, Vec<Box<dyn Array>>, Bitmap)> {
     if !field.dtype().is_nested() || field.is_pl_pq_empty_struct() {
         let pages = columns.pop().unwrap();
         init.push(InitNested::Primitive(field.is_nullable));
         let type_ = types.pop().unwrap();
-        let (nested, arr, pdm) = page_iter_to_array(pages, type_, field, filter, Some(init))?;
+        let (nested, arr, pdm) =
+            page_iter_to_array(pages, type_, field, filter, Some(init), binary_options)?;
         Ok((nested.unwrap(), arr, pdm))
     } else {
         match field.dtype() {
@@ -35,6 +37,7 @@ pub fn columns_to_iter_recursive(
                     inner.as_ref().clone(),
                     init,
                     filter,
+                    binary_options,
                 )?;
                 let array = array
                     .into_iter()
@@ -50,6 +53,7 @@ pub fn columns_to_iter_recursive(
                     inner.as_ref().clone(),
                     init,
                     filter,
+                    binary_options,
                 )?;
                 let array = array
                     .into_iter()
@@ -87,6 +91,7 @@ pub fn columns_to_iter_recursive(
                             struct_field.clone(),
                             init,
                             filter.clone(),
+                            binary_options,
                         )
                     };
 
@@ -141,6 +146,7 @@ pub fn columns_to_iter_recursive(
                     inner.as_ref().clone(),
                     init,
                     filter,
+                    binary_options,
                 )?;
                 let array = array
                     .into_iter()
@@ -167,7 +173,7 @@ pub fn columns_to_iter_recursive(
                         &field.name,
                         columns.pop().unwrap(),
                         ArrowDataType::Utf8View,
-                        binview::BinViewDecoder::new_string(),
+                        binview::BinViewDecoder::new_string().with_options(binary_options),
                         Some(init),
                     )?
                     .collect_nested(filter)?;
@@ -226,6 +232,7 @@ pub fn columns_to_iter_recursive(
                     field.with_dtype(ext.inner.clone()),
                     init,
                     filter,
+                    binary_options,
                 )?;
 
                 // Restore the extension type.
@@ -20,6 +20,7 @@ use crate::parquet::schema::types::{
 };
 use crate::parquet::types::int96_to_i64_ns;
 use crate::read::ParquetError;
+use crate::read::deserialize::binview::BinaryDecodeOptions;
 use crate::read::deserialize::categorical::CategoricalDecoder;
 use crate::read::deserialize::utils::PageDecoder;
 use crate::read::deserialize::{binary, binview};
@@ -32,6 +33,7 @@ pub fn page_iter_to_array(
     field: Field,
     filter: Option<Filter>,
     init_nested: Option<Vec<InitNested>>,
+    binary_options: &BinaryDecodeOptions,
 ) -> ParquetResult<(Option<NestedState>, Vec<Box<dyn Array>>, Bitmap)> {
     use ArrowDataType::*;
 
@@ -355,7 +357,9 @@ pub fn page_iter_to_array(
                 &field.name,
                 pages,
                 ArrowDataType::BinaryView,
-                binview::BinViewDecoder::new(false),
+                binview::BinViewDecoder::new(false)
+                    .with_column(type_)
+                    .with_options(binary_options),
                 init_nested,
             )?
             .collect(filter)?;
@@ -545,7 +549,9 @@ pub fn page_iter_to_array(
                 &field.name,
                 pages,
                 dtype,
-                binview::BinViewDecoder::new(is_string),
+                binview::BinViewDecoder::new(is_string)
+                    .with_column(type_)
+                    .with_options(binary_options),
                 init_nested,
             )?
             .collect_boxed(filter)?
@@ -557,7 +563,9 @@ pub fn page_iter_to_array(
                 &field.name,
                 pages,
                 dtype,
-                binview::BinViewDecoder::new(is_string),
+                binview::BinViewDecoder::new(is_string)
+                    .with_column(type_)
+                    .with_options(binary_options),
                 init_nested,
             )?
             .collect_boxed(filter)?
@@ -609,7 +617,9 @@ pub fn page_iter_to_array(
                     &field.name,
                     pages,
                     ArrowDataType::Utf8View,
-                    binview::BinViewDecoder::new_string(),
+                    binview::BinViewDecoder::new_string()
+                        .with_column(type_)
+                        .with_options(binary_options),
                     init_nested,
                 )?
                 .collect(filter)?;
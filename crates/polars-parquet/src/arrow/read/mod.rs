@@ -10,8 +10,9 @@ use std::io::{Read, Seek};
 
 use arrow::types::{NativeType, i256};
 pub use deserialize::{
-    Filter, InitNested, NestedState, PredicateFilter, column_iter_to_arrays, create_list,
-    create_map, get_page_iterator, init_nested, n_columns,
+    BinaryDecodeOptions, BinaryDecodeProgress, Filter, InitNested, NestedState, PredicateFilter,
+    column_iter_to_arrays, column_iter_to_arrays_with_options, create_list, create_map,
+    decode_plain_with_callback, get_page_iterator, init_nested, n_columns, plain_length_histogram,
 };
 #[cfg(feature = "async")]
 use futures::{AsyncRead, AsyncSeek};
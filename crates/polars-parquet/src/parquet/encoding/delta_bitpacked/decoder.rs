@@ -69,6 +69,7 @@ struct MiniBlock<'a> {
     unpacked_end: usize,
 }
 
+#[allow(dead_code)]
 pub(crate) struct SumGatherer(pub(crate) usize);
 
 pub trait DeltaGatherer {
@@ -1,6 +1,5 @@
 use super::super::delta_bitpacked;
-use crate::parquet::encoding::delta_bitpacked::SumGatherer;
-use crate::parquet::error::ParquetResult;
+use crate::parquet::error::{ParquetError, ParquetResult};
 
 /// Decodes according to [Delta strings](https://github.com/apache/parquet-format/blob/master/Encodings.md#delta-strings-delta_byte_array--7),
 /// prefixes, lengths and values
@@ -40,18 +39,89 @@ impl<'a> Decoder<'a> {
         self.prefix_lengths.len()
     }
 
+    /// Skip `n` values.
+    ///
+    /// Every value is front-coded against the previous one, so the skipped values are still
+    /// walked to keep track of the last one. Their lengths are decoded in chunks into a buffer on
+    /// the stack and only `last` is updated, nothing is allocated for the skipped values.
     pub fn skip_in_place(&mut self, n: usize) -> ParquetResult<()> {
-        let mut prefix_sum = 0usize;
-        self.prefix_lengths
-            .gather_n_into(&mut prefix_sum, n, &mut SumGatherer(0))?;
-        let mut suffix_sum = 0usize;
-        self.suffix_lengths
-            .gather_n_into(&mut suffix_sum, n, &mut SumGatherer(0))?;
-        self.offset += prefix_sum + suffix_sum;
+        let mut prefix_lengths = LengthBuffer::new();
+        let mut suffix_lengths = LengthBuffer::new();
+
+        let oos = || ParquetError::oos("DeltaBytes prefix or suffix length out of bounds");
+        let mut num_remaining = n.min(self.len());
+        while num_remaining > 0 {
+            let chunk_size = num_remaining.min(SKIP_CHUNK_SIZE);
+            prefix_lengths.clear();
+            suffix_lengths.clear();
+            self.prefix_lengths
+                .collect_n(&mut prefix_lengths, chunk_size)?;
+            self.suffix_lengths
+                .collect_n(&mut suffix_lengths, chunk_size)?;
+
+            for (&prefix_length, &suffix_length) in prefix_lengths
+                .as_slice()
+                .iter()
+                .zip(suffix_lengths.as_slice())
+            {
+                let prefix_length = usize::try_from(prefix_length).map_err(|_| oos())?;
+                let suffix_length = usize::try_from(suffix_length).map_err(|_| oos())?;
+                let end = self
+                    .offset
+                    .checked_add(suffix_length)
+                    .filter(|end| *end <= self.values.len())
+                    .ok_or_else(oos)?;
+                if prefix_length > self.last.len() {
+                    return Err(oos());
+                }
+
+                self.last.truncate(prefix_length);
+                self.last.extend_from_slice(&self.values[self.offset..end]);
+                self.offset = end;
+            }
+
+            num_remaining -= chunk_size;
+        }
         Ok(())
     }
 }
 
+/// The number of prefix and suffix lengths that are decoded at once when skipping values.
+const SKIP_CHUNK_SIZE: usize = 64;
+
+/// A buffer on the stack for at most [`SKIP_CHUNK_SIZE`] lengths.
+#[derive(Debug)]
+struct LengthBuffer {
+    lengths: [i64; SKIP_CHUNK_SIZE],
+    len: usize,
+}
+
+impl LengthBuffer {
+    fn new() -> Self {
+        Self {
+            lengths: [0; SKIP_CHUNK_SIZE],
+            len: 0,
+        }
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    fn as_slice(&self) -> &[i64] {
+        &self.lengths[..self.len]
+    }
+}
+
+impl Extend<i64> for LengthBuffer {
+    fn extend<I: IntoIterator<Item = i64>>(&mut self, iter: I) {
+        for length in iter {
+            self.lengths[self.len] = length;
+            self.len += 1;
+        }
+    }
+}
+
 impl Iterator for Decoder<'_> {
     type Item = ParquetResult<Vec<u8>>;
 
@@ -7,6 +7,7 @@ pub use encoder::encode;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parquet::encoding::delta_bitpacked;
     use crate::parquet::error::ParquetError;
 
     #[test]
@@ -24,4 +25,76 @@ mod tests {
         assert_eq!(values, b"Helloicopter");
         Ok(())
     }
+
+    #[test]
+    fn skip_in_place() -> Result<(), ParquetError> {
+        let data = vec![
+            b"Hello".as_ref(),
+            b"Helicopter",
+            b"Help",
+            b"Helpful",
+            b"Hi",
+            b"",
+            b"High",
+        ];
+        let mut buffer = vec![];
+        encode(data.clone().into_iter(), &mut buffer);
+
+        for k in 0..=data.len() {
+            let mut decoder = Decoder::try_new(&buffer)?;
+            decoder.skip_in_place(k)?;
+            assert_eq!(decoder.len(), data.len() - k);
+
+            let rest = decoder.collect::<Result<Vec<_>, _>>()?;
+            let expected = data[k..].iter().map(|v| v.to_vec()).collect::<Vec<_>>();
+            assert_eq!(rest, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn skip_in_place_many() -> Result<(), ParquetError> {
+        // Enough values to be skipped in several chunks.
+        let data = (0..200)
+            .map(|i| format!("value{}", i / 3).into_bytes())
+            .collect::<Vec<_>>();
+        let mut buffer = vec![];
+        encode(data.iter().map(|v| v.as_slice()), &mut buffer);
+
+        for k in [63, 64, 65, 130, 199, 200] {
+            let mut decoder = Decoder::try_new(&buffer)?;
+            decoder.skip_in_place(k)?;
+            assert_eq!(decoder.len(), data.len() - k);
+
+            let rest = decoder.collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(rest, data[k..]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn skip_in_place_negative_lengths() -> Result<(), ParquetError> {
+        // A corrupt page, encoded by hand with the given prefix and suffix lengths.
+        let page = |prefix_lengths: &[i64], suffix_lengths: &[i64]| {
+            let mut buffer = vec![];
+            delta_bitpacked::encode(prefix_lengths.iter().copied(), &mut buffer, 1);
+            delta_bitpacked::encode(suffix_lengths.iter().copied(), &mut buffer, 1);
+            buffer.extend_from_slice(b"ab");
+            buffer
+        };
+
+        let buffer = page(&[0, 1], &[2, 0]);
+        Decoder::try_new(&buffer)?.skip_in_place(2)?;
+
+        let buffer = page(&[0, 0], &[2, -1]);
+        assert!(Decoder::try_new(&buffer)?.skip_in_place(2).is_err());
+
+        let buffer = page(&[0, -1], &[2, 0]);
+        assert!(Decoder::try_new(&buffer)?.skip_in_place(2).is_err());
+
+        let buffer = page(&[0], &[i64::MAX]);
+        assert!(Decoder::try_new(&buffer)?.skip_in_place(1).is_err());
+
+        Ok(())
+    }
 }
@@ -81,6 +81,9 @@ pub struct PageReader {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    // Whether to verify the CRC32 checksum of pages that have one.
+    verify_crc: bool,
 }
 
 impl PageReader {
@@ -114,9 +117,17 @@ impl PageReader {
             descriptor: reader_meta.descriptor,
             scratch,
             max_page_size,
+            verify_crc: false,
         }
     }
 
+    /// Verify the CRC32 checksum of every page that has one before it is returned, erroring on a
+    /// mismatch. Pages without a checksum are not checked. Off by default.
+    pub fn with_crc_verification(mut self, verify_crc: bool) -> Self {
+        self.verify_crc = verify_crc;
+        self
+    }
+
     /// Returns the reader and this Readers' interval buffer
     pub fn into_inner(self) -> (MemReader, Vec<u8>) {
         (self.reader, self.scratch)
@@ -160,6 +171,10 @@ impl PageReader {
             ));
         }
 
+        if self.verify_crc {
+            verify_page_crc(&page_header, &buffer)?;
+        }
+
         finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
             if let CompressedPage::Dict(d) = p {
                 Some(d)
@@ -228,9 +243,28 @@ pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<Compre
         ));
     }
 
+    if reader.verify_crc {
+        verify_page_crc(&page_header, &buffer)?;
+    }
+
     finish_page(page_header, buffer, reader.compression, &reader.descriptor).map(Some)
 }
 
+/// Check the CRC32 checksum of the (compressed) page data against the one in its header, if any.
+fn verify_page_crc(page_header: &ParquetPageHeader, data: &[u8]) -> ParquetResult<()> {
+    let Some(expected) = page_header.crc else {
+        return Ok(());
+    };
+    let actual = crc32fast::hash(data) as i32;
+    if actual != expected {
+        return Err(ParquetError::oos(format!(
+            "page checksum mismatch: the header has CRC {expected:#010x}, but the page data has \
+            CRC {actual:#010x}"
+        )));
+    }
+    Ok(())
+}
+
 pub(super) fn finish_page(
     page_header: ParquetPageHeader,
     data: MemSlice,
@@ -350,3 +384,89 @@ pub(super) fn get_page_num_values(header: &ParquetPageHeader) -> ParquetResult<i
         _ => 0,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page_header(data: &[u8], crc: Option<i32>) -> ParquetPageHeader {
+        ParquetPageHeader {
+            type_: polars_parquet_format::PageType::DATA_PAGE,
+            uncompressed_page_size: data.len() as i32,
+            compressed_page_size: data.len() as i32,
+            crc,
+            data_page_header: None,
+            index_page_header: None,
+            dictionary_page_header: None,
+            data_page_header_v2: None,
+        }
+    }
+
+    #[test]
+    fn verify_crc() {
+        let data = b"some page data";
+        let crc = crc32fast::hash(data) as i32;
+
+        assert!(verify_page_crc(&page_header(data, Some(crc)), data).is_ok());
+        assert!(verify_page_crc(&page_header(data, None), data).is_ok());
+
+        let mut corrupted = data.to_vec();
+        corrupted[3] ^= 1;
+        let err = verify_page_crc(&page_header(data, Some(crc)), &corrupted).unwrap_err();
+        assert!(err.to_string().contains("page checksum mismatch"));
+    }
+
+    #[test]
+    fn read_page_with_corrupted_crc() -> ParquetResult<()> {
+        use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+
+        use crate::parquet::schema::types::{PhysicalType, PrimitiveType};
+
+        // A column chunk with a single plain encoded `i32` page.
+        let data = 7i32.to_le_bytes();
+        let mut page_header = page_header(&data, Some(crc32fast::hash(&data) as i32));
+        page_header.data_page_header = Some(polars_parquet_format::DataPageHeader {
+            num_values: 1,
+            encoding: polars_parquet_format::Encoding::PLAIN,
+            definition_level_encoding: polars_parquet_format::Encoding::RLE,
+            repetition_level_encoding: polars_parquet_format::Encoding::RLE,
+            statistics: None,
+        });
+        let mut column_chunk = vec![];
+        page_header.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut column_chunk))?;
+        column_chunk.extend_from_slice(&data);
+
+        let read = |column_chunk: Vec<u8>, verify_crc: bool| {
+            let meta = PageMetaData::new(
+                0,
+                1,
+                Compression::Uncompressed,
+                Descriptor {
+                    primitive_type: PrimitiveType::from_physical("a".into(), PhysicalType::Int32),
+                    max_def_level: 0,
+                    max_rep_level: 0,
+                },
+            );
+            PageReader::new_with_page_meta(
+                MemReader::from_vec(column_chunk),
+                meta,
+                vec![],
+                usize::MAX,
+            )
+            .with_crc_verification(verify_crc)
+            .next()
+            .unwrap()
+        };
+
+        assert!(read(column_chunk.clone(), true).is_ok());
+
+        // Flip a bit in the page data.
+        let last = column_chunk.len() - 1;
+        column_chunk[last] ^= 1;
+        assert!(read(column_chunk.clone(), false).is_ok());
+        let err = read(column_chunk, true).unwrap_err();
+        assert!(err.to_string().contains("page checksum mismatch"));
+
+        Ok(())
+    }
+}
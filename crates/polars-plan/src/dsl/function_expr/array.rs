@@ -38,7 +38,9 @@ pub enum ArrayFunction {
     CountMatches,
     Shift,
     Explode(ExplodeOptions),
-    Concat,
+    Concat {
+        list_width: Option<usize>,
+    },
     #[cfg(feature = "array_to_struct")]
     ToStruct(Option<super::DslNameGenerator>),
 }
@@ -47,7 +49,7 @@ impl fmt::Display for ArrayFunction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
         use ArrayFunction::*;
         let name = match self {
-            Concat => "concat",
+            Concat { .. } => "concat",
             Length => "length",
             Slice(_, _) => "slice",
             Min => "min",
@@ -59,13 +59,16 @@ pub fn concat_list<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(s: E) -> PolarsResult
 }
 
 /// Horizontally concatenate columns into a single array-type column.
-pub fn concat_arr(input: Vec<Expr>) -> PolarsResult<Expr> {
+///
+/// List columns are single elements of the output, unless `list_width` is given. Then they are
+/// flattened and every non-null list must have `list_width` elements.
+pub fn concat_arr(input: Vec<Expr>, list_width: Option<usize>) -> PolarsResult<Expr> {
     feature_gated!("dtype-array", {
         polars_ensure!(!input.is_empty(), ComputeError: "`concat_arr` needs one or more expressions");
 
         Ok(Expr::Function {
             input,
-            function: FunctionExpr::ArrayExpr(ArrayFunction::Concat),
+            function: FunctionExpr::ArrayExpr(ArrayFunction::Concat { list_width }),
         })
     })
 }
@@ -1,5 +1,6 @@
 use polars_core::utils::slice_offsets;
 use polars_ops::chunked_array::array::*;
+use polars_ops::series::concat_arr::concat_arr_output_dtype;
 
 use super::*;
 
@@ -35,7 +36,9 @@ pub enum IRArrayFunction {
     CountMatches,
     Shift,
     Explode(ExplodeOptions),
-    Concat,
+    Concat {
+        list_width: Option<usize>,
+    },
     Slice(i64, i64),
     #[cfg(feature = "array_to_struct")]
     ToStruct(Option<DslNameGenerator>),
@@ -45,13 +48,14 @@ impl IRArrayFunction {
     pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
         use IRArrayFunction::*;
         match self {
-            Concat => Ok(Field::new(
+            Concat { list_width } => Ok(Field::new(
                 mapper
                     .args()
                     .first()
                     .map_or(PlSmallStr::EMPTY, |x| x.name.clone()),
                 concat_arr_output_dtype(
                     &mut mapper.args().iter().map(|x| (x.name.as_str(), &x.dtype)),
+                    *list_width,
                 )?,
             )),
             Length => mapper.with_dtype(IDX_DTYPE),
@@ -109,7 +113,7 @@ impl IRArrayFunction {
             A::Contains { nulls_equal: _ } => FunctionOptions::elementwise(),
             #[cfg(feature = "array_count")]
             A::CountMatches => FunctionOptions::elementwise(),
-            A::Concat => FunctionOptions::elementwise()
+            A::Concat { .. } => FunctionOptions::elementwise()
                 .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION),
             A::Length
             | A::Min
@@ -170,7 +174,7 @@ impl Display for IRArrayFunction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use IRArrayFunction::*;
         let name = match self {
-            Concat => "concat",
+            Concat { .. } => "concat",
             Length => "length",
             Min => "min",
             Max => "max",
@@ -205,42 +209,3 @@ impl Display for IRArrayFunction {
         write!(f, "arr.{name}")
     }
 }
-
-/// Determine the output dtype of a `concat_arr` operation. Also performs validation to ensure input
-/// dtypes are compatible.
-fn concat_arr_output_dtype(
-    inputs: &mut dyn ExactSizeIterator<Item = (&str, &DataType)>,
-) -> PolarsResult<DataType> {
-    #[allow(clippy::len_zero)]
-    if inputs.len() == 0 {
-        // should not be reachable - we did not set ALLOW_EMPTY_INPUTS
-        panic!();
-    }
-
-    let mut inputs = inputs.map(|(name, dtype)| {
-        let (inner_dtype, width) = match dtype {
-            DataType::Array(inner, width) => (inner.as_ref(), *width),
-            dt => (dt, 1),
-        };
-        (name, dtype, inner_dtype, width)
-    });
-    let (first_name, first_dtype, first_inner_dtype, mut out_width) = inputs.next().unwrap();
-
-    for (col_name, dtype, inner_dtype, width) in inputs {
-        out_width += width;
-
-        if inner_dtype != first_inner_dtype {
-            polars_bail!(
-                SchemaMismatch:
-                "concat_arr dtype mismatch: expected {} or array[{}] dtype to match dtype of first \
-                input column (name: {}, dtype: {}), got {} instead for column {}",
-                first_inner_dtype, first_inner_dtype, first_name, first_dtype, dtype, col_name,
-            )
-        }
-    }
-
-    Ok(DataType::Array(
-        Box::new(first_inner_dtype.clone()),
-        out_width,
-    ))
-}
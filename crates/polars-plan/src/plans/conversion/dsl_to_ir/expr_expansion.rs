@@ -94,7 +94,7 @@ fn function_input_wildcard_expansion(function: &FunctionExpr) -> FunctionExpansi
     );
     #[cfg(feature = "dtype-array")]
     {
-        expand_into_inputs |= matches!(function, F::ArrayExpr(ArrayFunction::Concat));
+        expand_into_inputs |= matches!(function, F::ArrayExpr(ArrayFunction::Concat { .. }));
     }
     #[cfg(feature = "dtype-struct")]
     {
@@ -89,7 +89,7 @@ pub(super) fn convert_functions(
                 A::CountMatches => IA::CountMatches,
                 A::Shift => IA::Shift,
                 A::Explode(options) => IA::Explode(options),
-                A::Concat => IA::Concat,
+                A::Concat { list_width } => IA::Concat { list_width },
                 A::Slice(offset, length) => IA::Slice(offset, length),
                 #[cfg(feature = "array_to_struct")]
                 A::ToStruct(ng) => IA::ToStruct(ng),
@@ -288,7 +288,7 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::ArrayExpr(f) => {
             use {ArrayFunction as A, IRArrayFunction as IA};
             F::ArrayExpr(match f {
-                IA::Concat => A::Concat,
+                IA::Concat { list_width } => A::Concat { list_width },
                 IA::Length => A::Length,
                 IA::Min => A::Min,
                 IA::Max => A::Max,
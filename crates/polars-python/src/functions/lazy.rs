@@ -222,9 +222,9 @@ pub fn concat_list(s: Vec<PyExpr>) -> PyResult<PyExpr> {
 }
 
 #[pyfunction]
-pub fn concat_arr(s: Vec<PyExpr>) -> PyResult<PyExpr> {
+pub fn concat_arr(s: Vec<PyExpr>, list_width: Option<usize>) -> PyResult<PyExpr> {
     let s = s.into_iter().map(|e| e.inner).collect::<Vec<_>>();
-    let expr = dsl::concat_arr(s).map_err(PyPolarsErr::from)?;
+    let expr = dsl::concat_arr(s, list_width).map_err(PyPolarsErr::from)?;
     Ok(expr.into())
 }
 
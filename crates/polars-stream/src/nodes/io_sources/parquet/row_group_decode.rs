@@ -267,6 +267,7 @@ fn decode_column(
         columns_to_deserialize,
         arrow_field.clone(),
         filter,
+        false,
     )?;
 
     if !skip_num_rows_check {
@@ -658,6 +659,7 @@ fn decode_column_prefiltered(
         columns_to_deserialize,
         arrow_field.clone(),
         deserialize_filter,
+        false,
     )?;
 
     let mut series = Series::try_from((arrow_field, array))?;
@@ -1,6 +1,6 @@
 use std::hint;
 
-use arrow::array::{BooleanArray, FixedSizeListArray, ListArray, ValueSize};
+use arrow::array::{BooleanArray, FixedSizeListArray, ListArray, PrimitiveArray, ValueSize};
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::{ArrowDataType, Field};
 use arrow::offset::{Offsets, OffsetsBuffer};
@@ -121,6 +121,7 @@ fn bench_concat_list_and_array(c: &mut Criterion) {
                             Box::new(DataType::Boolean),
                             (*item_capacity * columns_count) as usize,
                         ),
+                        None,
                     )
                 })
             },
@@ -128,5 +129,110 @@ fn bench_concat_list_and_array(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, bench_concat_list_and_array);
+fn create_int_lists_for_test(
+    values_len: usize,
+    width: usize,
+    columns_count: usize,
+    ragged: bool,
+) -> impl Iterator<Item = ListChunked> {
+    // Ragged rows alternate between `width - 1` and `width + 1` elements, so both layouts hold
+    // the same number of values.
+    let lengths = (0..values_len / width).map(move |i| {
+        if ragged {
+            width - 1 + 2 * (i % 2)
+        } else {
+            width
+        }
+    });
+    let offsets: OffsetsBuffer<i64> = Offsets::<i64>::try_from_lengths(lengths).unwrap().into();
+
+    (0..columns_count).map(move |_| {
+        let values = PrimitiveArray::from_vec((0..*offsets.last()).collect::<Vec<i64>>());
+        let list_array = ListArray::new(
+            ArrowDataType::LargeList(Box::new(Field::new(
+                PlSmallStr::EMPTY,
+                ArrowDataType::Int64,
+                true,
+            ))),
+            offsets.clone(),
+            Box::new(values),
+            None,
+        );
+
+        ListChunked::with_chunk(PlSmallStr::EMPTY, list_array)
+    })
+}
+
+fn bench_lst_concat_uniform_widths(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lst_concat_uniform_widths");
+    for width in [2, 10, 100] {
+        for (name, ragged) in [("uniform", false), ("ragged", true)] {
+            let mut lists = create_int_lists_for_test(100_000, width, 10, ragged);
+            let first_list = lists.next().unwrap();
+            let other_series: Vec<_> = lists.map(|ca| ca.into_column()).collect();
+            group.bench_with_input(
+                BenchmarkId::from_parameter(format!("{name}-{width}")),
+                &(first_list, other_series.as_slice()),
+                |b, (f, o)| b.iter(|| black_box(f.lst_concat(o))),
+            );
+        }
+    }
+}
+
+fn create_int_arrays_for_test(
+    height: usize,
+    width: usize,
+    columns_count: usize,
+    with_nulls: bool,
+) -> impl Iterator<Item = ArrayChunked> {
+    (0..columns_count).map(move |_| {
+        let values_len = height * width;
+        // A single null value is enough to leave the fast path of `horizontal_flatten`.
+        let validity = with_nulls.then(|| Bitmap::from_iter((0..values_len).map(|i| i % 7 != 0)));
+        let values = PrimitiveArray::new(
+            ArrowDataType::Int64,
+            (0..values_len as i64).collect::<Vec<_>>().into(),
+            validity,
+        );
+
+        let fs_list_array = FixedSizeListArray::new(
+            ArrowDataType::FixedSizeList(
+                Box::new(Field::new(PlSmallStr::EMPTY, ArrowDataType::Int64, true)),
+                width,
+            ),
+            height,
+            Box::new(values),
+            None,
+        );
+
+        ArrayChunked::with_chunk(PlSmallStr::EMPTY, fs_list_array)
+    })
+}
+
+fn bench_concat_arr_nulls(c: &mut Criterion) {
+    let columns_count = 10;
+    let height = 10_000;
+    let mut group = c.benchmark_group("concat_arr_nulls");
+    for width in [1, 10, 100] {
+        let dtype = DataType::Array(Box::new(DataType::Int64), width * columns_count);
+        for (name, with_nulls) in [("no_nulls", false), ("nulls", true)] {
+            let arrays: Vec<_> =
+                create_int_arrays_for_test(height, width, columns_count, with_nulls)
+                    .map(|ca| ca.into_column())
+                    .collect();
+            group.bench_with_input(
+                BenchmarkId::from_parameter(format!("{name}-{width}")),
+                arrays.as_slice(),
+                |b, arrays| b.iter(|| black_box(concat_arr(arrays, &dtype, None))),
+            );
+        }
+    }
+}
+
+criterion_group!(
+    benches,
+    bench_concat_list_and_array,
+    bench_lst_concat_uniform_widths,
+    bench_concat_arr_nulls
+);
 criterion_main!(benches);
@@ -162,6 +162,6 @@ pub fn read_columns_many<R: Read + Seek>(
     field_columns
         .into_iter()
         .zip(fields.iter_values().cloned())
-        .map(|(columns, field)| to_deserializer(columns.clone(), field, filter.clone()))
+        .map(|(columns, field)| to_deserializer(columns.clone(), field, filter.clone(), false))
         .collect()
 }
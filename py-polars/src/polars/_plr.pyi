@@ -2094,7 +2094,7 @@ def concat_lf(
     seq: Any, rechunk: bool, parallel: bool, to_supertypes: bool, maintain_order: bool
 ) -> PyLazyFrame: ...
 def concat_list(s: Sequence[PyExpr]) -> PyExpr: ...
-def concat_arr(s: Sequence[PyExpr]) -> PyExpr: ...
+def concat_arr(s: Sequence[PyExpr], list_width: int | None) -> PyExpr: ...
 def concat_str(s: Sequence[PyExpr], separator: str, ignore_nulls: bool) -> PyExpr: ...
 def len() -> PyExpr: ...
 def cov(a: PyExpr, b: PyExpr, ddof: int) -> PyExpr: ...
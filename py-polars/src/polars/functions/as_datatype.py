@@ -500,12 +500,17 @@ def concat_list(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) ->
     return wrap_expr(plr.concat_list(exprs))
 
 
-def concat_arr(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) -> Expr:
+def concat_arr(
+    exprs: IntoExpr | Iterable[IntoExpr],
+    *more_exprs: IntoExpr,
+    list_width: int | None = None,
+) -> Expr:
     """
     Horizontally concatenate columns into a single array column.
 
     Non-array columns are reshaped to a unit-width array. All columns must have
-    a dtype of either `pl.Array(<DataType>, width)` or `pl.<DataType>`.
+    a dtype of either `pl.Array(<DataType>, width)` or `pl.<DataType>`. List
+    columns are single elements of the output, unless `list_width` is set.
 
     .. warning::
             This functionality is considered **unstable**. It may be changed
@@ -520,6 +525,10 @@ def concat_arr(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) -> E
     *more_exprs
         Additional columns to concatenate into a single array column, specified as
         positional arguments.
+    list_width
+        Flatten list columns into `list_width` elements instead of keeping each
+        list as a single element. Every non-null list must have exactly
+        `list_width` elements.
 
     Examples
     --------
@@ -615,7 +624,7 @@ def concat_arr(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) -> E
     issue_unstable_warning(msg)
 
     exprs = parse_into_list_of_expressions(exprs, *more_exprs)
-    return wrap_expr(plr.concat_arr(exprs))
+    return wrap_expr(plr.concat_arr(exprs, list_width))
 
 
 @overload
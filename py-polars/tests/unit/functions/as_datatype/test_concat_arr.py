@@ -198,3 +198,39 @@ def test_concat_arr_expansion_23267() -> None:
     out = df.select(z=pl.concat_arr(pl.all())).to_series()
 
     assert_series_equal(out, pl.Series("z", [[1, 2]], dtype=pl.Array(pl.Int64, 2)))
+
+
+def test_concat_arr_list() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2], [3], None],
+            "b": [[4, 5], [6, 7], [8, 9]],
+            "c": [[1, 2], None, [3, 4]],
+        }
+    )
+
+    # Lists are single elements by default.
+    out = df.select(pl.concat_arr("a", "b")).to_series()
+    assert_series_equal(
+        out,
+        pl.Series(
+            "a",
+            [[[1, 2], [4, 5]], [[3], [6, 7]], [None, [8, 9]]],
+            dtype=pl.Array(pl.List(pl.Int64), 2),
+        ),
+    )
+
+    # With a list width they are flattened, null lists give null rows.
+    q = df.lazy().select(pl.concat_arr("c", "b", list_width=2))
+    assert q.collect_schema() == {"c": pl.Array(pl.Int64, 4)}
+    assert_series_equal(
+        q.collect().to_series(),
+        pl.Series(
+            "c",
+            [[1, 2, 4, 5], None, [3, 4, 8, 9]],
+            dtype=pl.Array(pl.Int64, 4),
+        ),
+    )
+
+    with pytest.raises(ShapeError, match="has length 1 at row 1, expected length 2"):
+        df.select(pl.concat_arr("a", "b", list_width=2))